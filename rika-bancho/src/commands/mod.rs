@@ -1 +1,2 @@
 pub mod submit;
+pub mod verify;
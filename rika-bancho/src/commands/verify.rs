@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use kani_kani::KaniContext;
+use lexicon::t_prefix;
+use rika_model::osu::verification::{verify_link_code, VerificationError};
+
+use crate::{error::RikaBanchoError, KaniLocale, RikaData};
+
+pub async fn verify(ctx: Arc<KaniContext<RikaData>>) -> Result<(), RikaBanchoError> {
+    let KaniContext {
+        args, data, sender, ..
+    } = ctx.as_ref();
+
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.verify);
+
+    let Some(code) = args.first() else {
+        let command = format!("{}verify <code>", data.prefix);
+
+        return ctx
+            .say(&t!(missing_code).r(command))
+            .await
+            .map_err(|_| RikaBanchoError::Fallthrough);
+    };
+
+    let reply = match verify_link_code(&data.shared.db, data.shared.link_policy, code, sender).await
+    {
+        Ok(username) => t!(verified).r(username),
+        Err(VerificationError::InvalidCode) => t!(invalid_code).clone(),
+        Err(VerificationError::WrongAccount(username)) => t!(wrong_account).r(username),
+        Err(VerificationError::AlreadyClaimed(username)) => {
+            i18n.r(|v| &v.osu.link.already_claimed).r(username)
+        }
+        Err(e @ VerificationError::Sqlx(..)) => return Err(anyhow!(e))?,
+    };
+
+    ctx.say(&reply)
+        .await
+        .map_err(|_| RikaBanchoError::Fallthrough)?;
+
+    Ok(())
+}
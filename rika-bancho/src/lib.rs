@@ -3,7 +3,7 @@ pub mod error;
 
 use std::{sync::Arc, vec};
 
-use commands::submit::submit;
+use commands::{submit::submit, verify::verify};
 use error::handle_error;
 use kani_kani::{BoxedError, KaniContext, KaniFramework};
 use lexicon::{LocaleAccess, Localizer};
@@ -26,6 +26,7 @@ struct RikaConfig {
 
 pub struct RikaData {
     shared: Arc<SharedRika>,
+    prefix: String,
 }
 
 pub type RikaContext = KaniContext<RikaData>;
@@ -41,13 +42,16 @@ pub async fn run(shared: Arc<SharedRika>) -> Result<(), BoxedError> {
         irc_token: config.irc_token,
     };
 
-    let data = RikaData { shared };
+    let data = RikaData {
+        shared,
+        prefix: config.prefix.clone(),
+    };
 
     let kani_kani = KaniFramework {
         config: bancho_config,
         data,
         prefix: config.prefix,
-        commands: vec![(vec!["submit"], &submit)],
+        commands: vec![(vec!["submit"], &submit), (vec!["verify"], &verify)],
         on_error: &handle_error,
     };
 
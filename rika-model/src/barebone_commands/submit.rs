@@ -55,6 +55,7 @@ pub async fn submit_barebones(
     if let Ok(result) = submit_result.await {
         result.map_err(|e| match e {
             SubmissionError::IdLocker(..) => anyhow!(t!(already_submitting).clone()).into(),
+            SubmissionError::OptedOut => anyhow!(t!(opted_out).clone()).into(),
             e => e,
         })?;
     }
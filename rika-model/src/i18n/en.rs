@@ -1,8 +1,34 @@
 use lexicon::*;
 
 use super::rika_localizer::{
+    config::{show::Show, Config},
     math::{calc::Calc, Math},
-    osu::{link::Link, recommend::Recommend, submit::Submit, Osu},
+    osu::{
+        announce::Announce,
+        compare::Compare,
+        forget::Forget,
+        import::{replay::Replay, stable::Stable, Import},
+        leaderboard::Leaderboard,
+        link::Link,
+        playlist::Playlist,
+        privacy::Privacy,
+        profile::Profile,
+        progress::Progress,
+        recommend::{
+            axis::Axis, feedback::Feedback, reason::Reason, strategy::Strategy, Recommend,
+        },
+        rivals::Rivals,
+        submit::Submit,
+        top::Top,
+        top_scores::TopScores,
+        unlink::Unlink,
+        verify::Verify,
+        Osu,
+    },
+    owner::{
+        scraper::{status::Status, Scraper},
+        Owner,
+    },
     rate::Rate,
     user::{
         avatar::{footer::Footer, Avatar},
@@ -23,19 +49,219 @@ impl DefaultLocalizer for RikaLocalizer {
             osu: Osu {
                 link: Link {
                     failed: r!(|who| "Failed to link to {who}"),
-                    linked: r!(|who| "Linked to osu! account {who}"),
+                    code: r!(|(who, command, bot, minutes)| {
+                        "To link {who}, send {command} to {bot} on osu! within {minutes} minutes."
+                    }),
+                    already_claimed: r!(|who| {
+                        "{who} is already linked to someone else, they can /osu unlink it first."
+                    }),
+                },
+                unlink: Unlink {
+                    unlinked: r!(|who| "Unlinked {who} from your Discord account."),
+                },
+                announce: Announce {
+                    top_play: r!(|who| "{who} set a new top play!"),
+                    pp: r!("Performance"),
+                    position: r!("Position"),
+                    position_of: r!(|position| "#{position} of their top 100"),
+                    skills: r!("Skills"),
+                },
+                verify: Verify {
+                    verified: r!(|who| "Linked {who} to your Discord account!"),
+                    missing_code: r!(|command| "Send {command} with the code from /osu link."),
+                    invalid_code: r!("This code is invalid or expired, try /osu link again."),
+                    wrong_account: r!(|who| "This code can only be sent by {who}."),
                 },
                 submit: Submit {
                     submitted: r!("Submitted scores successfully!"),
                     too_long_warning: r!("This might take a while"),
                     progress_shower: r!(|(amount, out_of)| "Submitted {amount}/{out_of} scores."),
                     already_submitting: r!("Your scores are already being submitted! Please wait."),
+                    opted_out: r!(
+                        "This account opted out of having its scores stored! See `/osu privacy`."
+                    ),
+                },
+                privacy: Privacy {
+                    opted_out: r!(|who| {
+                        "{who} will not be scraped nor have scores submitted anymore!"
+                    }),
+                    opted_in: r!(|who| "{who} can be scraped and have scores submitted again!"),
+                },
+                forget: Forget {
+                    forgotten: r!(|who| {
+                        "Forgot every link to {who} and their scores! They stay opted out, \
+                        so they won't be scraped or have scores submitted again."
+                    }),
                 },
                 recommend: Recommend {
                     recommendation: r!(|(link, mods)| {
                         "I recommend you to play {link} with the following mods: {mods}"
                     }),
                     not_found: r!("Could not find any map to recommend for you!"),
+                    invalid_mods: r!(|(mods, valid)| {
+                        "{mods} are not valid mods! Try a combination of {valid}"
+                    }),
+                    page: r!(|(page, out_of)| "Recommendation {page}/{out_of}"),
+                    closeness: r!("Match"),
+                    axis: Axis {
+                        aim: r!("Aim"),
+                        speed: r!("Speed"),
+                        accuracy: r!("Accuracy"),
+                        flashlight: r!("Flashlight"),
+                        difficulty: r!("Difficulty"),
+                        overall: r!("Overall"),
+                    },
+                    reason: Reason {
+                        skills: r!("Your skills"),
+                        candidate: r!("This score"),
+                        player: r!("Played by"),
+                        player_got: r!(|(player, pp)| "{player} got {pp} on it"),
+                    },
+                    strategy: Strategy {
+                        title: r!("Strategy"),
+                        comfort: r!("Comfort: maps around your current skill."),
+                        push: r!(|(amount, axis)| {
+                            "Push: {amount} harder on {axis}, your weakest skill."
+                        }),
+                        farm: r!("Farm: maps that give a lot of pp for their star rating."),
+                    },
+                    feedback: Feedback {
+                        played_it: r!("Played it"),
+                        too_easy: r!("Too easy"),
+                        too_hard: r!("Too hard"),
+                        not_my_style: r!("Not my style"),
+                        thanks: r!(
+                            "Thanks! I will keep that in mind for your next recommendations."
+                        ),
+                    },
+                },
+                profile: Profile {
+                    title: r!(|who| "{who}'s skills"),
+                    skills: r!("Skills"),
+                    overall: r!("Weighted pp"),
+                    scores: r!("Stored scores"),
+                    last_submitted: r!("Last submission"),
+                    percentile: r!(|percent| "better than {percent} of players"),
+                },
+                progress: Progress {
+                    title: r!("Skill progress"),
+                    period: r!(|(from, to)| "From {from} to {to}"),
+                    not_enough: r!(
+                        "There is not enough history yet! Submit your scores again later with `/osu submit`"
+                    ),
+                },
+                compare: Compare {
+                    title: r!(|(a, b)| "{a} vs {b}"),
+                    difference: r!("Difference"),
+                    not_found: r!(|who| {
+                        "Could not find {who}, is it a linked user or an osu! username?"
+                    }),
+                    not_submitted: r!(|who| "{who} has no stored scores on this mode yet!"),
+                },
+                leaderboard: Leaderboard {
+                    title: r!(|axis| "{axis} leaderboard"),
+                    invalid_axis: r!(|axis| "This mode has no {axis} skill!"),
+                    empty: r!("Nobody in this server has submitted scores on this mode yet!"),
+                    new_entry: r!("new"),
+                },
+                rivals: Rivals {
+                    title: r!(|who| "Players closest to {who}"),
+                    empty: r!("Could not find anyone with skills like yours!"),
+                    guild_only: r!("Rivals in this server can only be found inside a server!"),
+                },
+                top: Top {
+                    title: r!(|(axis, scope)| "Best {axis} players in {scope}"),
+                    global: r!("the world"),
+                    empty: r!("Nobody was ranked there yet! Try again after the next scrape."),
+                },
+                top_scores: TopScores {
+                    title: r!(|(who, axis)| "Stored scores of {who} by {axis}"),
+                    empty: r!("You have no stored scores on this mode yet! Try `/osu submit`"),
+                },
+                playlist: Playlist {
+                    built: r!(|amount| "Here is a playlist with {amount} maps for you!"),
+                    collection_name: r!(|who| "rika recommendations for {who}"),
+                    not_found: r!("Could not find any maps to build a playlist for you!"),
+                },
+                import: Import {
+                    replay: Replay {
+                        malformed: r!("This does not look like a valid osu! replay file."),
+                        beatmap_not_found: r!(
+                            "Could not find the beatmap this replay was played on."
+                        ),
+                        wrong_player: r!(|who| {
+                            "This replay was played by {who}, not by your linked account."
+                        }),
+                        already_imported: r!("This score was already submitted!"),
+                        outside_top: r!(|link| {
+                            "Your score on {link} is not among your best 100, so it was not kept."
+                        }),
+                        imported: r!(|link| "Imported your score on {link}!"),
+                    },
+                    stable: Stable {
+                        malformed: r!("This does not look like a valid osu! scores.db file."),
+                        no_scores: r!(|who| "Could not find any scores set by {who}."),
+                        importing: r!("Importing your scores, this might take a while"),
+                        imported: r!(|(amount, missing)| {
+                            "Imported {amount} new scores! {missing} beatmaps could not be found."
+                        }),
+                    },
+                },
+            },
+            owner: Owner {
+                scraper: Scraper {
+                    status: Status {
+                        title: r!("Scraper status"),
+                        state: r!("State"),
+                        running: r!("Running"),
+                        paused: r!("Paused"),
+                        ranking: r!("Ranking"),
+                        ranking_of: r!(|(mode, scope)| "{mode} in {scope}"),
+                        world: r!("the world"),
+                        idle: r!("Starting a pass"),
+                        page: r!("Page"),
+                        processed: r!("Users processed"),
+                        errors: r!("Errors"),
+                        eta: r!("Pass finishes"),
+                        unknown: r!("Unknown"),
+                    },
+                    paused: r!("Paused the scraper!"),
+                    already_paused: r!("The scraper is already paused!"),
+                    resumed: r!("Resumed the scraper!"),
+                    already_running: r!("The scraper is already running!"),
+                    countries_set: r!(|countries| {
+                        "Scraping {countries} from the next pass until a restart!"
+                    }),
+                    modes_set: r!(|modes| "Scraping {modes} from the next pass until a restart!"),
+                    invalid_country: r!(|country| "{country} is not a country code!"),
+                },
+            },
+            config: Config {
+                command_disabled: r!(|command| "{command} is turned off in this server."),
+                mode_set: r!(|mode| "osu! commands now default to {mode} in this server!"),
+                mode_reset: r!("osu! commands default to osu! again in this server!"),
+                locale_set: r!(|locale| "I will reply in {locale} in this server!"),
+                locale_reset: r!("I will reply in the language of each user again!"),
+                announcement_set: r!(|(channel, mode)| {
+                    "New {mode} top plays will be posted in {channel}!"
+                }),
+                announcement_off: r!("New top plays will not be posted anymore."),
+                range_set: r!(|range| "Recommendations now default to a range of {range}!"),
+                range_reset: r!(|range| "Recommendations default to a range of {range} again!"),
+                disabled: r!(|command| "Turned off {command} in this server!"),
+                enabled: r!(|command| "Turned {command} back on in this server!"),
+                not_disabled: r!(|command| "{command} is not turned off in this server!"),
+                unknown_command: r!(|command| "There is no command called {command}!"),
+                show: Show {
+                    title: r!("Server settings"),
+                    mode: r!("Default mode"),
+                    locale: r!("Language"),
+                    per_user: r!("Each user's own"),
+                    announcement: r!("Top play announcements"),
+                    off: r!("Off"),
+                    range: r!("Recommendation range"),
+                    disabled_commands: r!("Turned off commands"),
+                    none: r!("None"),
                 },
             },
             user: User {
@@ -61,9 +287,9 @@ impl DefaultLocalizer for RikaLocalizer {
                         vec!["Wowie, they should open a... you know it!"],
                         vec!["OH GOD! give it to me, gimme gimme (a man of the midnight)"],
                     ]
-                        .iter()
-                        .map(|v| v.iter().map(|l| l.to_string()).collect())
-                        .collect(),
+                    .iter()
+                    .map(|v| v.iter().map(|l| l.to_string()).collect())
+                    .collect(),
                 ),
             },
         }
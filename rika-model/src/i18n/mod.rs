@@ -16,17 +16,158 @@ nestruct::nest! {
         osu: {
             link: {
                 failed: lexicon::GR<String>?,
-                linked: lexicon::GR<String>?
+                code: lexicon::GR<(String, String, String, u32)>?,
+                already_claimed: lexicon::GR<String>?
+            },
+            unlink: {
+                unlinked: lexicon::GR<String>?
+            },
+            announce: {
+                top_play: lexicon::GR<String>?,
+                pp: lexicon::R?,
+                position: lexicon::R?,
+                position_of: lexicon::GR<usize>?,
+                skills: lexicon::R?
+            },
+            verify: {
+                verified: lexicon::GR<String>?,
+                missing_code: lexicon::GR<String>?,
+                invalid_code: lexicon::R?,
+                wrong_account: lexicon::GR<String>?
             },
             submit: {
                 submitted: lexicon::R?,
                 too_long_warning: lexicon::R?,
                 progress_shower: lexicon::GR<(usize, usize)>?,
-                already_submitting: lexicon::R?
+                already_submitting: lexicon::R?,
+                opted_out: lexicon::R?
+            },
+            privacy: {
+                opted_out: lexicon::GR<String>?,
+                opted_in: lexicon::GR<String>?
+            },
+            forget: {
+                forgotten: lexicon::GR<String>?
             },
             recommend: {
                 recommendation: lexicon::GR<(String, String)>?,
+                not_found: lexicon::R?,
+                invalid_mods: lexicon::GR<(String, String)>?,
+                page: lexicon::GR<(usize, usize)>?,
+                closeness: lexicon::R?,
+                axis: {
+                    aim: lexicon::R?,
+                    speed: lexicon::R?,
+                    accuracy: lexicon::R?,
+                    flashlight: lexicon::R?,
+                    difficulty: lexicon::R?,
+                    overall: lexicon::R?
+                },
+                reason: {
+                    skills: lexicon::R?,
+                    candidate: lexicon::R?,
+                    player: lexicon::R?,
+                    player_got: lexicon::GR<(String, String)>?
+                },
+                strategy: {
+                    title: lexicon::R?,
+                    comfort: lexicon::R?,
+                    push: lexicon::GR<(String, String)>?,
+                    farm: lexicon::R?
+                },
+                feedback: {
+                    played_it: lexicon::R?,
+                    too_easy: lexicon::R?,
+                    too_hard: lexicon::R?,
+                    not_my_style: lexicon::R?,
+                    thanks: lexicon::R?
+                }
+            },
+            profile: {
+                title: lexicon::GR<String>?,
+                skills: lexicon::R?,
+                overall: lexicon::R?,
+                scores: lexicon::R?,
+                last_submitted: lexicon::R?,
+                percentile: lexicon::GR<String>?
+            },
+            progress: {
+                title: lexicon::R?,
+                period: lexicon::GR<(String, String)>?,
+                not_enough: lexicon::R?
+            },
+            compare: {
+                title: lexicon::GR<(String, String)>?,
+                difference: lexicon::R?,
+                not_found: lexicon::GR<String>?,
+                not_submitted: lexicon::GR<String>?
+            },
+            leaderboard: {
+                title: lexicon::GR<String>?,
+                invalid_axis: lexicon::GR<String>?,
+                empty: lexicon::R?,
+                new_entry: lexicon::R?
+            },
+            rivals: {
+                title: lexicon::GR<String>?,
+                empty: lexicon::R?,
+                guild_only: lexicon::R?
+            },
+            top: {
+                title: lexicon::GR<(String, String)>?,
+                global: lexicon::R?,
+                empty: lexicon::R?
+            },
+            top_scores: {
+                title: lexicon::GR<(String, String)>?,
+                empty: lexicon::R?
+            },
+            playlist: {
+                built: lexicon::GR<usize>?,
+                collection_name: lexicon::GR<String>?,
                 not_found: lexicon::R?
+            },
+            import: {
+                replay: {
+                    malformed: lexicon::R?,
+                    beatmap_not_found: lexicon::R?,
+                    wrong_player: lexicon::GR<String>?,
+                    already_imported: lexicon::R?,
+                    outside_top: lexicon::GR<String>?,
+                    imported: lexicon::GR<String>?
+                },
+                stable: {
+                    malformed: lexicon::R?,
+                    no_scores: lexicon::GR<String>?,
+                    importing: lexicon::R?,
+                    imported: lexicon::GR<(usize, usize)>?
+                }
+            }
+        },
+        owner: {
+            scraper: {
+                status: {
+                    title: lexicon::R?,
+                    state: lexicon::R?,
+                    running: lexicon::R?,
+                    paused: lexicon::R?,
+                    ranking: lexicon::R?,
+                    ranking_of: lexicon::GR<(String, String)>?,
+                    world: lexicon::R?,
+                    idle: lexicon::R?,
+                    page: lexicon::R?,
+                    processed: lexicon::R?,
+                    errors: lexicon::R?,
+                    eta: lexicon::R?,
+                    unknown: lexicon::R?
+                },
+                paused: lexicon::R?,
+                already_paused: lexicon::R?,
+                resumed: lexicon::R?,
+                already_running: lexicon::R?,
+                countries_set: lexicon::GR<String>?,
+                modes_set: lexicon::GR<String>?,
+                invalid_country: lexicon::GR<String>?
             }
         },
         user: {
@@ -37,6 +178,32 @@ nestruct::nest! {
                 }
             }
         },
+        config: {
+            command_disabled: lexicon::GR<String>?,
+            mode_set: lexicon::GR<String>?,
+            mode_reset: lexicon::R?,
+            locale_set: lexicon::GR<String>?,
+            locale_reset: lexicon::R?,
+            announcement_set: lexicon::GR<(String, String)>?,
+            announcement_off: lexicon::R?,
+            range_set: lexicon::GR<String>?,
+            range_reset: lexicon::GR<String>?,
+            disabled: lexicon::GR<String>?,
+            enabled: lexicon::GR<String>?,
+            not_disabled: lexicon::GR<String>?,
+            unknown_command: lexicon::GR<String>?,
+            show: {
+                title: lexicon::R?,
+                mode: lexicon::R?,
+                locale: lexicon::R?,
+                per_user: lexicon::R?,
+                announcement: lexicon::R?,
+                off: lexicon::R?,
+                range: lexicon::R?,
+                disabled_commands: lexicon::R?,
+                none: lexicon::R?
+            }
+        },
         rate: {
             rated: lexicon::GR<(String, String)>?,
             feedback: Vec<Vec<String>>?
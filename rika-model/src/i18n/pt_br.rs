@@ -1,6 +1,7 @@
 use lexicon::*;
 
 use super::rika_localizer::{
+    osu::{forget::Forget, recommend::Recommend, Osu},
     user::{
         avatar::{footer::Footer, Avatar},
         User,
@@ -10,6 +11,21 @@ use super::rika_localizer::{
 
 pub fn locale_pt_br() -> RikaLocalizer {
     RikaLocalizer {
+        osu: Osu {
+            forget: Forget {
+                forgotten: r!(|who| {
+                    "Esqueci todos os vínculos com {who} e seus scores! A conta continua com \
+                    opt-out, então não vai ser escaneada nem ter scores enviados de novo."
+                }),
+            },
+            recommend: Recommend {
+                invalid_mods: r!(|(mods, valid)| {
+                    "{mods} não são mods válidos! Tente uma combinação de {valid}"
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
         user: User {
             avatar: Avatar {
                 footer: Footer {
@@ -4,7 +4,8 @@ use std::sync::Arc;
 
 use i18n::{rika_localizer::RikaLocalizer, RikaLocale};
 use lexicon::Localizer;
-use osu::{beatmap::BeatmapCache, submit::ScoreSubmitter};
+use osu::{beatmap::BeatmapCache, link::LinkPolicy, submit::ScoreSubmitter};
+use scraper::handle::ScraperHandle;
 use sqlx::MySqlPool;
 use tokio::sync::RwLock;
 
@@ -12,6 +13,7 @@ pub mod barebone_commands;
 pub mod i18n;
 pub mod osu;
 pub mod rika_cord;
+pub mod scraper;
 
 pub struct SharedRika {
     pub db: MySqlPool,
@@ -19,4 +21,6 @@ pub struct SharedRika {
     pub score_submitter: Arc<RwLock<ScoreSubmitter>>,
     pub beatmap_cache: BeatmapCache,
     pub locales: Localizer<RikaLocale, RikaLocalizer>,
+    pub scraper: ScraperHandle,
+    pub link_policy: LinkPolicy,
 }
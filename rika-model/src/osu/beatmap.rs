@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use fchashmap::FcHashMap;
+use md5::{Digest, Md5};
 use strum::Display;
 use tokio::sync::Mutex;
 
@@ -8,6 +9,8 @@ use tokio::sync::Mutex;
 pub struct BeatmapCache {
     pub client: reqwest::Client,
     pub cache: Arc<Mutex<FcHashMap<u32, Arc<[u8]>, 256>>>,
+    pub checksums: Arc<Mutex<FcHashMap<String, u32, 256>>>,
+    pub mappers: Arc<Mutex<FcHashMap<u32, u32, 256>>>,
 }
 
 #[derive(thiserror::Error, Display, Debug)]
@@ -26,6 +29,8 @@ impl BeatmapCache {
         Self {
             client: reqwest::Client::new(),
             cache: Arc::new(Mutex::new(FcHashMap::new())),
+            checksums: Arc::new(Mutex::new(FcHashMap::new())),
+            mappers: Arc::new(Mutex::new(FcHashMap::new())),
         }
     }
 
@@ -55,4 +60,54 @@ impl BeatmapCache {
 
         Ok(map_bytes)
     }
+
+    /// The id of the beatmap with the given checksum, alongside the id of its mapper.
+    pub async fn get_beatmap_ids(
+        &self,
+        rosu: &rosu_v2::Osu,
+        checksum: &str,
+    ) -> Result<(u32, u32), BeatmapCacheError> {
+        let cached_map_id = self.checksums.lock().await.get(checksum).copied();
+
+        if let Some(map_id) = cached_map_id {
+            if let Some(mapper_id) = self.mappers.lock().await.get(&map_id) {
+                return Ok((map_id, *mapper_id));
+            }
+        }
+
+        // Checksums cached by exports only know the beatmap, so it is looked up by id instead.
+        let beatmap = match cached_map_id {
+            Some(map_id) => rosu.beatmap().map_id(map_id).await,
+            None => rosu.beatmap().checksum(checksum).await,
+        }
+        .map_err(|e| BeatmapCacheError::Failed(anyhow::Error::from(e)))?;
+
+        let _ = self
+            .checksums
+            .lock()
+            .await
+            .insert(checksum.to_string(), beatmap.map_id);
+
+        let _ = self
+            .mappers
+            .lock()
+            .await
+            .insert(beatmap.map_id, beatmap.creator_id);
+
+        Ok((beatmap.map_id, beatmap.creator_id))
+    }
+
+    /// The MD5 of a beatmap's `.osu` file, which is what osu!stable identifies beatmaps by.
+    pub async fn get_beatmap_checksum(&self, beatmap_id: u32) -> Result<String, BeatmapCacheError> {
+        let beatmap_file = self.get_beatmap_file(beatmap_id).await?;
+        let checksum = format!("{:x}", Md5::digest(&beatmap_file));
+
+        let _ = self
+            .checksums
+            .lock()
+            .await
+            .insert(checksum.clone(), beatmap_id);
+
+        Ok(checksum)
+    }
 }
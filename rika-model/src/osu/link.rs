@@ -0,0 +1,90 @@
+use serde::Deserialize;
+use sqlx::{MySql, MySqlConnection, Pool};
+use strum::Display;
+
+/// Whether an osu! account may be linked to more than one discord account.
+#[derive(Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkPolicy {
+    #[default]
+    Exclusive,
+    Shared,
+}
+
+#[derive(Display, Clone, Copy)]
+#[strum(serialize_all = "lowercase")]
+pub enum LinkAction {
+    Link,
+    Unlink,
+}
+
+/// Whether the policy forbids linking the osu! account because another discord account verified
+/// it, unverified links are replaced by the first verified one.
+pub async fn is_claimed_by_other(
+    db: &Pool<MySql>,
+    policy: LinkPolicy,
+    osu_id: u32,
+    discord_id: &str,
+) -> Result<bool, sqlx::Error> {
+    if policy == LinkPolicy::Shared {
+        return Ok(false);
+    }
+
+    let claimed = sqlx::query_scalar!(
+        "
+        SELECT EXISTS(
+            SELECT 1 FROM rika_user
+            WHERE osu_id = ? AND discord_id != ? AND verified_at IS NOT NULL
+        )
+        ",
+        &osu_id,
+        discord_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(claimed != 0)
+}
+
+pub async fn log_link_action(
+    conn: &mut MySqlConnection,
+    discord_id: &str,
+    osu_id: u32,
+    action: LinkAction,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "INSERT INTO link_event (discord_id, osu_id, action) VALUES (?, ?, ?)",
+        discord_id,
+        &osu_id,
+        &action.to_string()
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Keeps the rika_user row, only the link itself is removed.
+pub async fn unlink(db: &Pool<MySql>, discord_id: &str, osu_id: u32) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    remove_link(&mut *tx, discord_id, osu_id).await?;
+
+    tx.commit().await
+}
+
+/// [`unlink`] as part of a larger transaction.
+pub async fn remove_link(
+    conn: &mut MySqlConnection,
+    discord_id: &str,
+    osu_id: u32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE rika_user SET osu_id = NULL, verified_at = NULL WHERE discord_id = ?",
+        discord_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    log_link_action(conn, discord_id, osu_id, LinkAction::Unlink).await
+}
@@ -1,2 +1,7 @@
 pub mod beatmap;
+pub mod link;
+pub mod skills;
+pub mod snapshot;
+pub mod stable;
 pub mod submit;
+pub mod verification;
@@ -0,0 +1,148 @@
+use itertools::Itertools;
+use rosu_v2::prelude::GameMode;
+use sqlx::{MySql, Pool, QueryBuilder, Row};
+
+use super::submit::SubmittableMode;
+
+/// A user's weighted overall pp and weighted value on every skill axis of a mode.
+pub type SkillVector = (f32, Vec<(&'static str, f32)>);
+
+/// Averages values sorted from the best score down, each one worth 95% of the previous.
+pub fn weighted_average(values: impl Iterator<Item = f32>) -> f32 {
+    let (pp_sum, weight) = values
+        .enumerate()
+        .map(|(i, value)| (value, 0.95f32.powi(i as i32)))
+        .map(|(value, weight_by)| (value * weight_by, weight_by))
+        .fold((0f32, 0f32), |(pp_sum, weight), (value, weight_by)| {
+            (pp_sum + value, weight + weight_by)
+        });
+
+    pp_sum / weight
+}
+
+pub fn get_weighter<T>(vec: Vec<T>) -> impl Fn(for<'a> fn(&'a T) -> f32) -> f32 {
+    move |f: fn(&T) -> f32| weighted_average(vec.iter().map(f))
+}
+
+/// The weighted skills of the user on the given mode, if they have any stored score on it.
+pub async fn weighted_skills(
+    db: &Pool<MySql>,
+    osu_id: u32,
+    submit_mode: SubmittableMode,
+) -> Result<Option<SkillVector>, sqlx::Error> {
+    let rows = sqlx::query(&format!(
+        "
+        SELECT pp.* FROM osu_score s
+        JOIN {submit_mode}_performance pp ON s.id = pp.score_id
+        WHERE s.osu_user_id = ? AND s.mode = ?
+        ORDER BY pp.overall DESC
+        "
+    ))
+    .bind(osu_id)
+    .bind(GameMode::from(submit_mode) as i16)
+    .fetch_all(db)
+    .await?;
+
+    if rows.is_empty() {
+        return Ok(None);
+    }
+
+    let weigh = |axis: &str| {
+        rows.iter()
+            .map(|row| row.try_get::<f32, _>(axis))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|values| weighted_average(values.into_iter()))
+    };
+
+    let skills = submit_mode
+        .axes()
+        .iter()
+        .map(|axis| Ok((*axis, weigh(axis)?)))
+        .collect::<Result<_, sqlx::Error>>()?;
+
+    Ok(Some((weigh("overall")?, skills)))
+}
+
+/// Pushes a `weighted` table holding the weighted skills of every user with stored scores on
+/// the mode, or only of the given user, one row per `osu_user_id` with a column per axis and
+/// `overall`.
+pub fn push_weighted_skills(
+    query: &mut QueryBuilder<'_, MySql>,
+    submit_mode: SubmittableMode,
+    osu_id: Option<u32>,
+) {
+    let columns = submit_mode
+        .axes()
+        .iter()
+        .chain(&["overall"])
+        .map(|axis| format!("pp.{axis}"))
+        .join(", ");
+
+    let weighted = submit_mode
+        .axes()
+        .iter()
+        .chain(&["overall"])
+        .map(|axis| format!("SUM({axis} * weight) / SUM(weight) AS {axis}"))
+        .join(", ");
+
+    query.push(format!(
+        "
+        WITH ranked AS (
+            SELECT s.osu_user_id, {columns}, POW(0.95, ROW_NUMBER() OVER (
+                PARTITION BY s.osu_user_id ORDER BY pp.overall DESC
+            ) - 1) AS weight
+            FROM osu_score s
+            JOIN {submit_mode}_performance pp ON s.id = pp.score_id
+            WHERE s.mode = "
+    ));
+
+    query.push_bind(GameMode::from(submit_mode) as i16);
+
+    if let Some(osu_id) = osu_id {
+        query.push(" AND s.osu_user_id = ").push_bind(osu_id);
+    }
+
+    query.push(format!(
+        "
+        ), weighted AS (
+            SELECT osu_user_id, {weighted} FROM ranked GROUP BY osu_user_id
+        ) "
+    ));
+}
+
+/// Recomputes the cached ranking of every user on every axis of every mode.
+pub async fn refresh_skill_ranking(db: &Pool<MySql>) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    for submit_mode in [
+        SubmittableMode::Osu,
+        SubmittableMode::Taiko,
+        SubmittableMode::Mania,
+    ] {
+        let mode_bits = GameMode::from(submit_mode) as i16;
+
+        sqlx::query!("DELETE FROM skill_ranking WHERE mode = ?", &mode_bits)
+            .execute(&mut *tx)
+            .await?;
+
+        let mut query = QueryBuilder::<MySql>::new(
+            "INSERT INTO skill_ranking (mode, axis, osu_user_id, value)",
+        );
+
+        push_weighted_skills(&mut query, submit_mode, None);
+
+        let mut selects = query.separated(" UNION ALL ");
+
+        for axis in submit_mode.axes().iter().chain(&["overall"]) {
+            selects.push("SELECT ");
+            selects.push_bind_unseparated(mode_bits);
+            selects.push_unseparated(format!(", '{axis}', osu_user_id, {axis} FROM weighted"));
+        }
+
+        query.build().execute(&mut *tx).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
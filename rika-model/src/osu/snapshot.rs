@@ -0,0 +1,36 @@
+use rosu_v2::prelude::GameMode;
+use sqlx::{MySql, Pool, QueryBuilder};
+
+use super::{skills::push_weighted_skills, submit::SubmittableMode};
+
+/// Stores the user's current weighted value on every axis of the mode, plus the overall pp.
+///
+/// Scores are weighted like the rankings do, sorted by overall pp with each one worth 95% of the
+/// previous. Snapshots are keyed by the second they were taken at, so a second one within the
+/// same second replaces the values of the first instead of failing the submission that took it.
+pub async fn take_skill_snapshot(
+    db: &Pool<MySql>,
+    osu_id: u32,
+    submit_mode: SubmittableMode,
+) -> Result<(), sqlx::Error> {
+    let mode_bits = GameMode::from(submit_mode) as i16;
+
+    let mut query =
+        QueryBuilder::<MySql>::new("INSERT INTO skill_snapshot (osu_user_id, mode, axis, value)");
+
+    push_weighted_skills(&mut query, submit_mode, Some(osu_id));
+
+    let mut selects = query.separated(" UNION ALL ");
+
+    for axis in submit_mode.axes().iter().chain(&["overall"]) {
+        selects.push("SELECT osu_user_id, ");
+        selects.push_bind_unseparated(mode_bits);
+        selects.push_unseparated(format!(", '{axis}', {axis} FROM weighted"));
+    }
+
+    query.push(" ON DUPLICATE KEY UPDATE value = VALUES(value)");
+
+    query.build().execute(db).await?;
+
+    Ok(())
+}
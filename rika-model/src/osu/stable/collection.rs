@@ -0,0 +1,79 @@
+use super::writer::StableWriter;
+
+/// The `collection.db` version written by recent osu!stable builds.
+const COLLECTION_VERSION: u32 = 20150203;
+
+pub struct Collection {
+    pub name: String,
+    pub beatmap_md5s: Vec<String>,
+}
+
+/// osu!stable's `collection.db`, named lists of beatmap MD5s.
+#[derive(Default)]
+pub struct CollectionDatabase {
+    pub collections: Vec<Collection>,
+}
+
+impl CollectionDatabase {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = StableWriter::new();
+
+        writer
+            .u32(COLLECTION_VERSION)
+            .u32(self.collections.len() as u32);
+
+        for collection in &self.collections {
+            writer
+                .string(&collection.name)
+                .u32(collection.beatmap_md5s.len() as u32);
+
+            for beatmap_md5 in &collection.beatmap_md5s {
+                writer.string(beatmap_md5);
+            }
+        }
+
+        writer.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Collection, CollectionDatabase, COLLECTION_VERSION};
+    use crate::osu::stable::reader::StableReader;
+
+    #[test]
+    fn collections_read_back() {
+        let database = CollectionDatabase {
+            collections: vec![
+                Collection {
+                    name: "rika's picks for peppy".to_string(),
+                    beatmap_md5s: vec![
+                        "d7e1002824cfe4e9c4c6a8e6fd1e4c1a".to_string(),
+                        "0b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e".to_string(),
+                    ],
+                },
+                Collection {
+                    name: String::new(),
+                    beatmap_md5s: vec![],
+                },
+            ],
+        };
+
+        let bytes = database.to_bytes();
+        let mut reader = StableReader::new(&bytes);
+
+        assert_eq!(reader.u32().unwrap(), COLLECTION_VERSION);
+        assert_eq!(reader.u32().unwrap(), 2);
+
+        for collection in &database.collections {
+            assert_eq!(reader.string().unwrap(), collection.name);
+            assert_eq!(reader.u32().unwrap(), collection.beatmap_md5s.len() as u32);
+
+            for beatmap_md5 in &collection.beatmap_md5s {
+                assert_eq!(&reader.string().unwrap(), beatmap_md5);
+            }
+        }
+
+        assert!(reader.take(1).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use itertools::Itertools;
+use rosu_v2::prelude::GameMode;
+
+use crate::{
+    osu::submit::{
+        ScoreSource, ScoreSubmitter, SubmissionError, SubmittableMode, SubmittableScore,
+    },
+    SharedRika,
+};
+
+use super::scores_db::ScoresDatabase;
+
+/// How many of the latest best scores of each mode are imported, since every one of them needs a
+/// beatmap lookup and its pp calculated before the best 100 can be told apart.
+pub const STABLE_IMPORT_LIMIT: usize = 150;
+
+pub struct StableImport {
+    pub imported: usize,
+    pub missing_beatmaps: usize,
+}
+
+/// Imports the best score of a player on each beatmap stored inside a `scores.db`, set under any
+/// of the given names, keeping to the latest [`STABLE_IMPORT_LIMIT`] of each mode.
+pub async fn import_scores_db(
+    data: &SharedRika,
+    osu_id: u32,
+    players: &[String],
+    database: &ScoresDatabase,
+) -> Result<StableImport, SubmissionError> {
+    let SharedRika {
+        rosu,
+        beatmap_cache,
+        score_submitter,
+        ..
+    } = data;
+
+    let best_scores = players
+        .iter()
+        .flat_map(|player| database.scores_by(player))
+        .filter(|s| SubmittableMode::try_from(s.mode).is_ok())
+        .into_grouping_map_by(|s| (s.beatmap_md5.as_str(), s.mode))
+        .max_by_key(|_, s| s.score)
+        .into_values()
+        .into_group_map_by(|s| s.mode)
+        .into_values()
+        .flat_map(|scores| {
+            scores
+                .into_iter()
+                .sorted_by_key(|s| Reverse(s.timestamp))
+                .take(STABLE_IMPORT_LIMIT)
+        });
+
+    let mut missing_beatmaps = 0;
+    let mut scores_by_mode: HashMap<GameMode, Vec<SubmittableScore>> = HashMap::new();
+
+    for score in best_scores {
+        match beatmap_cache
+            .get_beatmap_ids(rosu, &score.beatmap_md5)
+            .await
+        {
+            Ok((map_id, mapper_id)) => scores_by_mode
+                .entry(score.mode)
+                .or_default()
+                .push(score.submittable(map_id, mapper_id)),
+            Err(..) => missing_beatmaps += 1,
+        }
+    }
+
+    let mut imported = 0;
+
+    for (mode, scores) in scores_by_mode {
+        let (to_submit, ..) = ScoreSubmitter::begin_submission(score_submitter);
+
+        imported += to_submit
+            .import_scores(osu_id, mode, ScoreSource::Stable, scores)
+            .await?
+            .kept;
+    }
+
+    Ok(StableImport {
+        imported,
+        missing_beatmaps,
+    })
+}
@@ -0,0 +1,6 @@
+pub mod collection;
+pub mod import;
+pub mod reader;
+pub mod score;
+pub mod scores_db;
+pub mod writer;
@@ -0,0 +1,210 @@
+use strum::Display;
+
+#[derive(thiserror::Error, Display, Debug)]
+pub enum StableReadError {
+    UnexpectedEnd,
+    InvalidString,
+    UnsupportedMode,
+}
+
+/// Reads the little endian primitives used by every osu!stable binary format.
+pub struct StableReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+macro_rules! read_primitive {
+    ($($name:ident: $ty:ty),+) => {
+        $(
+            pub fn $name(&mut self) -> Result<$ty, StableReadError> {
+                let bytes = self.take(std::mem::size_of::<$ty>())?;
+                Ok(<$ty>::from_le_bytes(bytes.try_into().map_err(|_| StableReadError::UnexpectedEnd)?))
+            }
+        )+
+    };
+}
+
+impl<'a> StableReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub fn take(&mut self, amount: usize) -> Result<&'a [u8], StableReadError> {
+        let end = self
+            .position
+            .checked_add(amount)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(StableReadError::UnexpectedEnd)?;
+
+        let taken = &self.bytes[self.position..end];
+        self.position = end;
+
+        Ok(taken)
+    }
+
+    read_primitive!(
+        u8: u8,
+        u16: u16,
+        u32: u32,
+        i32: i32,
+        u64: u64,
+        i64: i64,
+        f64: f64
+    );
+
+    pub fn bool(&mut self) -> Result<bool, StableReadError> {
+        Ok(self.u8()? != 0)
+    }
+
+    pub fn uleb128(&mut self) -> Result<usize, StableReadError> {
+        let mut value = 0usize;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.u8()?;
+
+            value |= ((byte & 0x7F) as usize)
+                .checked_shl(shift)
+                .ok_or(StableReadError::InvalidString)?;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+
+            shift += 7;
+        }
+    }
+
+    /// Strings are either a lone `0x00` for an empty string, or `0x0b` followed by
+    /// an ULEB128 length and the UTF-8 contents.
+    pub fn string(&mut self) -> Result<String, StableReadError> {
+        match self.u8()? {
+            0x00 => Ok(String::new()),
+            0x0b => {
+                let length = self.uleb128()?;
+                let contents = self.take(length)?;
+
+                String::from_utf8(contents.to_vec()).map_err(|_| StableReadError::InvalidString)
+            }
+            _ => Err(StableReadError::InvalidString),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StableReadError, StableReader};
+    use crate::osu::stable::writer::StableWriter;
+
+    fn written(write: impl FnOnce(&mut StableWriter) -> &mut StableWriter) -> Vec<u8> {
+        let mut writer = StableWriter::new();
+        write(&mut writer);
+        writer.finish()
+    }
+
+    #[test]
+    fn uleb128_matches_known_encodings() {
+        assert_eq!(written(|w| w.uleb128(0)), [0x00]);
+        assert_eq!(written(|w| w.uleb128(127)), [0x7F]);
+        assert_eq!(written(|w| w.uleb128(128)), [0x80, 0x01]);
+        assert_eq!(written(|w| w.uleb128(300)), [0xAC, 0x02]);
+    }
+
+    #[test]
+    fn uleb128_round_trips() {
+        for value in [
+            0,
+            1,
+            127,
+            128,
+            16_383,
+            16_384,
+            2_097_152,
+            u32::MAX as usize,
+            usize::MAX,
+        ] {
+            let bytes = written(|w| w.uleb128(value));
+            let mut reader = StableReader::new(&bytes);
+
+            assert_eq!(reader.uleb128().unwrap(), value);
+            assert!(reader.take(1).is_err(), "{value} left bytes behind");
+        }
+    }
+
+    #[test]
+    fn uleb128_rejects_overlong_values() {
+        let bytes = [[0x80u8; 10].as_slice(), &[0x01]].concat();
+
+        assert!(matches!(
+            StableReader::new(&bytes).uleb128(),
+            Err(StableReadError::InvalidString)
+        ));
+    }
+
+    #[test]
+    fn empty_strings_are_a_lone_zero() {
+        let bytes = written(|w| w.string(""));
+
+        assert_eq!(bytes, [0x00]);
+        assert_eq!(StableReader::new(&bytes).string().unwrap(), "");
+    }
+
+    #[test]
+    fn strings_round_trip() {
+        let long = "a".repeat(128);
+
+        for value in ["peppy", "ピーピー", long.as_str()] {
+            let bytes = written(|w| w.string(value));
+            let mut reader = StableReader::new(&bytes);
+
+            assert_eq!(reader.string().unwrap(), value);
+            assert!(reader.take(1).is_err(), "{value} left bytes behind");
+        }
+
+        // The length counts bytes rather than characters, and needs a second byte past 127.
+        assert_eq!(written(|w| w.string("ピ"))[..2], [0x0b, 3]);
+        assert_eq!(written(|w| w.string(&long))[..3], [0x0b, 0x80, 0x01]);
+    }
+
+    #[test]
+    fn malformed_strings_fail() {
+        assert!(matches!(
+            StableReader::new(&[0x0c]).string(),
+            Err(StableReadError::InvalidString)
+        ));
+        assert!(matches!(
+            StableReader::new(&[0x0b, 5, b'p', b'e']).string(),
+            Err(StableReadError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            StableReader::new(&[0x0b, 2, 0xC3, 0x28]).string(),
+            Err(StableReadError::InvalidString)
+        ));
+        assert!(matches!(
+            StableReader::new(&[]).string(),
+            Err(StableReadError::UnexpectedEnd)
+        ));
+    }
+
+    #[test]
+    fn primitives_are_little_endian() {
+        let bytes = written(|w| {
+            w.u8(1)
+                .u16(0x0203)
+                .u32(0x0405_0607)
+                .i32(-2)
+                .u64(u64::MAX)
+                .i64(-3)
+        });
+
+        let mut reader = StableReader::new(&bytes);
+
+        assert_eq!(reader.u8().unwrap(), 1);
+        assert_eq!(reader.u16().unwrap(), 0x0203);
+        assert_eq!(reader.u32().unwrap(), 0x0405_0607);
+        assert_eq!(reader.i32().unwrap(), -2);
+        assert_eq!(reader.u64().unwrap(), u64::MAX);
+        assert_eq!(reader.i64().unwrap(), -3);
+        assert!(matches!(reader.u8(), Err(StableReadError::UnexpectedEnd)));
+    }
+}
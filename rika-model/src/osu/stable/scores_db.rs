@@ -0,0 +1,42 @@
+use super::{
+    reader::{StableReadError, StableReader},
+    score::StableScore,
+};
+
+/// osu!stable's `scores.db`, every locally set score grouped by beatmap MD5.
+#[derive(Debug)]
+pub struct ScoresDatabase {
+    pub version: u32,
+    pub beatmaps: Vec<(String, Vec<StableScore>)>,
+}
+
+impl ScoresDatabase {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, StableReadError> {
+        let mut reader = StableReader::new(bytes);
+
+        let version = reader.u32()?;
+        let beatmap_count = reader.u32()?;
+
+        let beatmaps = (0..beatmap_count)
+            .map(|_| {
+                let beatmap_md5 = reader.string()?;
+                let score_count = reader.u32()?;
+
+                let scores = (0..score_count)
+                    .map(|_| StableScore::read(&mut reader))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok((beatmap_md5, scores))
+            })
+            .collect::<Result<Vec<_>, StableReadError>>()?;
+
+        Ok(Self { version, beatmaps })
+    }
+
+    pub fn scores_by<'a>(&'a self, player: &'a str) -> impl Iterator<Item = &'a StableScore> {
+        self.beatmaps
+            .iter()
+            .flat_map(|(.., scores)| scores)
+            .filter(move |s| s.player.eq_ignore_ascii_case(player))
+    }
+}
@@ -0,0 +1,53 @@
+/// Writes the little endian primitives used by every osu!stable binary format.
+#[derive(Default)]
+pub struct StableWriter {
+    bytes: Vec<u8>,
+}
+
+macro_rules! write_primitive {
+    ($($name:ident: $ty:ty),+) => {
+        $(
+            pub fn $name(&mut self, value: $ty) -> &mut Self {
+                self.bytes.extend_from_slice(&value.to_le_bytes());
+                self
+            }
+        )+
+    };
+}
+
+impl StableWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    write_primitive!(u8: u8, u16: u16, u32: u32, i32: i32, u64: u64, i64: i64);
+
+    pub fn uleb128(&mut self, mut value: usize) -> &mut Self {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+
+            if value == 0 {
+                self.bytes.push(byte);
+                return self;
+            }
+
+            self.bytes.push(byte | 0x80);
+        }
+    }
+
+    pub fn string(&mut self, value: &str) -> &mut Self {
+        if value.is_empty() {
+            return self.u8(0x00);
+        }
+
+        self.u8(0x0b).uleb128(value.len());
+        self.bytes.extend_from_slice(value.as_bytes());
+
+        self
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+}
@@ -9,7 +9,8 @@ use rosu_pp::{
     taiko::TaikoPerformanceAttributes, ManiaPP, OsuPP, TaikoPP,
 };
 use rosu_v2::prelude::{GameMode, Score};
-use sqlx::{MySql, QueryBuilder};
+use serde::Deserialize;
+use sqlx::{MySql, MySqlPool, QueryBuilder};
 use strum::Display;
 use tokio::sync::{
     mpsc::{self, Receiver, Sender},
@@ -18,7 +19,10 @@ use tokio::sync::{
 
 use crate::SharedRika;
 
-use super::beatmap::BeatmapCacheError;
+use super::{
+    beatmap::{BeatmapCache, BeatmapCacheError},
+    snapshot::take_skill_snapshot,
+};
 
 #[derive(From)]
 pub enum SubmissionID {
@@ -26,14 +30,26 @@ pub enum SubmissionID {
     ByUsername(String),
 }
 
-#[derive(Display)]
+#[derive(Display, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
 pub enum SubmittableMode {
     Osu,
     Taiko,
     Mania,
 }
 
+impl SubmittableMode {
+    /// The skill axes stored in the performance table of the mode, besides the overall pp.
+    pub fn axes(&self) -> &'static [&'static str] {
+        match self {
+            Self::Osu => &["speed", "accuracy", "aim", "flashlight"],
+            Self::Taiko => &["accuracy", "difficulty"],
+            Self::Mania => &["difficulty"],
+        }
+    }
+}
+
 impl TryFrom<GameMode> for SubmittableMode {
     type Error = SubmissionError;
 
@@ -57,6 +73,91 @@ impl From<SubmittableMode> for GameMode {
     }
 }
 
+#[derive(Display, Clone, Copy)]
+#[strum(serialize_all = "lowercase")]
+pub enum ScoreSource {
+    Api,
+    Replay,
+    Stable,
+}
+
+/// The parts of a score required to calculate and store its performance.
+pub struct SubmittableScore {
+    pub id: u64,
+    pub map_id: u32,
+    pub mapper_id: Option<u32>,
+    pub mods: u32,
+    pub max_combo: u32,
+    pub count_geki: u32,
+    pub count_300: u32,
+    pub count_katu: u32,
+    pub count_100: u32,
+    pub count_50: u32,
+    pub count_miss: u32,
+}
+
+impl SubmittableScore {
+    pub fn from_api(score_id: u64, score: &Score) -> Self {
+        Self {
+            id: score_id,
+            map_id: score.map_id,
+            mapper_id: score.map.as_ref().map(|map| map.creator_id),
+            mods: score.mods.bits(),
+            max_combo: score.max_combo,
+            count_geki: score.statistics.count_geki,
+            count_300: score.statistics.count_300,
+            count_katu: score.statistics.count_katu,
+            count_100: score.statistics.count_100,
+            count_50: score.statistics.count_50,
+            count_miss: score.statistics.count_miss,
+        }
+    }
+}
+
+/// How many scores a submission brought in, and how many of them are still stored after it.
+#[derive(Default, Clone, Copy)]
+pub struct SubmittedScores {
+    /// Scores that were not stored before and had their performance calculated.
+    pub new: usize,
+    /// The new scores that made it into the best 100 of the user and were not evicted.
+    pub kept: usize,
+}
+
+/// A best score of the user, as the osu! api listed it on submission.
+pub struct TopPlay {
+    /// Starting at 1 for the best score of the user.
+    pub position: usize,
+    pub score: Score,
+    /// Whether the score was not stored before this submission.
+    pub new: bool,
+}
+
+#[derive(From)]
+pub enum BonkersferformanceAttributes {
+    Osu(OsuPerformanceAttributes),
+    Taiko(TaikoPerformanceAttributes),
+    Mania(ManiaPerformanceAttributes),
+}
+
+impl BonkersferformanceAttributes {
+    pub fn pp(&self) -> f64 {
+        match self {
+            Self::Osu(attributes) => attributes.pp,
+            Self::Taiko(attributes) => attributes.pp,
+            Self::Mania(attributes) => attributes.pp,
+        }
+    }
+
+    /// The star rating of the beatmap with the mods of the score.
+    pub fn stars(&self) -> f64 {
+        match self {
+            Self::Osu(attributes) => attributes.stars(),
+            Self::Taiko(attributes) => attributes.stars(),
+            Self::Mania(attributes) => attributes.stars(),
+        }
+    }
+}
+
 pub struct ScoreSubmitter {
     data: Option<Arc<SharedRika>>,
     locker: IDLocker,
@@ -81,6 +182,9 @@ pub enum SubmissionError {
     #[error("Missing dependencies.")]
     MissingDependencies,
 
+    #[error("This osu! account opted out of having its scores stored.")]
+    OptedOut,
+
     #[error(transparent)]
     Sqlx(sqlx::Error),
 
@@ -128,224 +232,442 @@ impl ScoreSubmitter {
 }
 
 impl ReadyScoreSubmitter {
+    /// Submits the best scores of the user, returning how many of them were new.
     pub async fn submit_scores(
         &self,
         osu_id: impl Into<SubmissionID>,
         mode: GameMode,
-    ) -> Result<(), SubmissionError> {
-        let submit_mode = SubmittableMode::try_from(mode)?;
+    ) -> Result<usize, SubmissionError> {
+        self.submit_top_plays(osu_id, mode)
+            .await
+            .map(|top_plays| top_plays.iter().filter(|play| play.new).count())
+    }
 
+    /// Submits the best scores of the user, returning all of them with the new ones marked.
+    pub async fn submit_top_plays(
+        &self,
+        osu_id: impl Into<SubmissionID>,
+        mode: GameMode,
+    ) -> Result<Vec<TopPlay>, SubmissionError> {
         let submitter = self.submitter.read().await;
 
         let Some(data) = &submitter.data else {
             return Err(SubmissionError::MissingDependencies)?;
         };
 
-        let SharedRika {
-            db,
-            rosu,
-            beatmap_cache,
-            ..
-        } = data.as_ref();
-
-        let mode_bits = mode as i16;
+        let SharedRika { rosu, .. } = data.as_ref();
 
         let osu_id = match osu_id.into() {
             SubmissionID::ByStoredID(id) => id,
             SubmissionID::ByUsername(username) => rosu.user(username).await?.user_id,
         };
 
+        ensure_opted_in(&data.db, osu_id).await?;
+
         let locker_guard = submitter.locker.lock(osu_id.to_string()).await?;
 
-        let osu_scores = rosu.user_scores(osu_id).limit(100).mode(mode).await?;
+        let submit_result = async {
+            let osu_scores = rosu.user_scores(osu_id).limit(100).mode(mode).await?;
+
+            let submit_mode = SubmittableMode::try_from(mode)?;
+
+            // Users without a stored profile have nothing to be scheduled by yet.
+            sqlx::query!(
+                "
+                INSERT INTO osu_user_mode (osu_user_id, mode, last_checked_at)
+                SELECT id, ?, NOW() FROM osu_user WHERE id = ?
+                ON DUPLICATE KEY UPDATE last_checked_at = NOW()
+                ",
+                &(mode as i16),
+                &osu_id
+            )
+            .execute(&data.db)
+            .await?;
+
+            // Comparing the ids is enough to skip users with no new top plays before any beatmap
+            // gets downloaded, and their skills could not have changed either.
+            let stored_ids = stored_score_ids(&data.db, osu_id, submit_mode).await?;
+
+            // The api lists the best scores first, so the index is the position in the top 100.
+            let top_plays = osu_scores
+                .into_iter()
+                .enumerate()
+                .map(|(i, score)| TopPlay {
+                    position: i + 1,
+                    new: score.score_id.is_some_and(|id| !stored_ids.contains(&id)),
+                    score,
+                })
+                .collect_vec();
+
+            if !top_plays.iter().any(|play| play.new) {
+                return Ok(top_plays);
+            }
+
+            let scores = top_plays
+                .iter()
+                .filter(|play| play.new)
+                .filter_map(|play| {
+                    play.score
+                        .score_id
+                        .map(|score_id| SubmittableScore::from_api(score_id, &play.score))
+                })
+                .collect_vec();
+
+            self.submit_prepared(data, osu_id, mode, ScoreSource::Api, scores)
+                .await?;
 
-        #[derive(sqlx::FromRow)]
-        struct ExistingScore {
-            id: u64,
+            evict_unlisted_top_plays(&data.db, osu_id, submit_mode, &top_plays).await?;
+
+            take_skill_snapshot(&data.db, osu_id, submit_mode).await?;
+
+            Ok::<_, SubmissionError>(top_plays)
         }
+        .await;
 
-        let rika_osu_scores: Vec<ExistingScore> = sqlx::query_as(&format!(
-            "
-			SELECT s.id FROM osu_score s
-			JOIN {submit_mode}_performance pp ON s.id = pp.score_id
-			WHERE s.osu_user_id = ?
-			"
-        ))
-        .bind(osu_id)
-        .fetch_all(db)
-        .await?;
+        locker_guard.unlock().await?;
+
+        submit_result
+    }
+
+    /// Submits scores that did not come from the osu! api, returning how many of them were new
+    /// and how many of those were kept.
+    pub async fn import_scores(
+        &self,
+        osu_id: u32,
+        mode: GameMode,
+        source: ScoreSource,
+        scores: Vec<SubmittableScore>,
+    ) -> Result<SubmittedScores, SubmissionError> {
+        let submitter = self.submitter.read().await;
 
-        let existing_scores: HashSet<_> = rika_osu_scores.into_iter().map(|s| s.id).collect();
+        let Some(data) = &submitter.data else {
+            return Err(SubmissionError::MissingDependencies)?;
+        };
 
-        let new_scores = osu_scores
-            .iter()
-            .filter_map(|s| {
-                s.score_id.and_then(|score_id| {
-                    let is_new = !existing_scores.contains(&score_id);
+        ensure_opted_in(&data.db, osu_id).await?;
 
-                    is_new.then_some((score_id, s))
-                })
-            })
+        let locker_guard = submitter.locker.lock(osu_id.to_string()).await?;
+
+        let submit_result = self
+            .submit_prepared(data, osu_id, mode, source, scores)
+            .await;
+
+        locker_guard.unlock().await?;
+
+        submit_result
+    }
+
+    async fn submit_prepared(
+        &self,
+        data: &SharedRika,
+        osu_id: u32,
+        mode: GameMode,
+        source: ScoreSource,
+        scores: Vec<SubmittableScore>,
+    ) -> Result<SubmittedScores, SubmissionError> {
+        let submit_mode = SubmittableMode::try_from(mode)?;
+
+        let SharedRika {
+            db, beatmap_cache, ..
+        } = data;
+
+        let existing_scores = stored_score_ids(db, osu_id, submit_mode).await?;
+
+        let new_scores = scores
+            .into_iter()
+            .filter(|s| !existing_scores.contains(&s.id))
             .collect_vec();
 
         if new_scores.is_empty() {
-            return Ok(());
+            return Ok(SubmittedScores::default());
         }
 
-        #[derive(From)]
-        enum BonkersferformanceAttributes {
-            Osu(OsuPerformanceAttributes),
-            Taiko(TaikoPerformanceAttributes),
-            Mania(ManiaPerformanceAttributes),
-        }
+        let new_scores_len = new_scores.len();
+        let mut performance_information = vec![];
 
-        let mut performance_information: Vec<(BonkersferformanceAttributes, (&Score, &u64))> =
-            vec![];
-
-        for (i, (score_id, score)) in new_scores.iter().enumerate() {
-            let beatmap_file = beatmap_cache.get_beatmap_file(score.map_id).await?;
-            let beatmap_rosu = rosu_pp::Beatmap::from_bytes(&beatmap_file).await?;
-
-            macro_rules! calc {
-			($mode:ident) => {
-				paste! {
-					[<$mode PP>]::new(&beatmap_rosu)
-				}
-				.mods(score.mods.into())
-				.n300(calc!(+count_300))
-				.n100(calc!(+count_100))
-				.n_misses(calc!(+count_miss))
-			};
-			(-$dep:ident) => {
-				score.$dep as usize
-			};
-			(+$dep:ident) => {
-				score.statistics.$dep as usize
-			};
-		}
-
-            let performance_attributes = match mode {
-                GameMode::Osu => Some(
-                    calc!(Osu)
-                        .n50(calc!(+count_50))
-                        .combo(calc!(-max_combo))
-                        .calculate()
-                        .into(),
-                ),
-                GameMode::Taiko => Some(calc!(Taiko).combo(calc!(-max_combo)).calculate().into()),
-                GameMode::Mania => Some(
-                    calc!(Mania)
-                        .n320(calc!(+count_geki))
-                        .n200(calc!(+count_katu))
-                        .calculate()
-                        .into(),
-                ),
-                _ => None,
-            };
-
-            if let Some(performance_attributes) = performance_attributes {
-                performance_information.push((performance_attributes, (*score, score_id)));
+        for (i, score) in new_scores.into_iter().enumerate() {
+            if let Some(performance_attributes) =
+                calculate_performance(beatmap_cache, mode, &score).await?
+            {
+                performance_information.push((performance_attributes, score));
 
                 let display_index = i + 1;
 
-                let _ = self.sender.send((display_index, new_scores.len())).await;
+                let _ = self.sender.send((display_index, new_scores_len)).await;
             }
         }
 
-        let mut scores_query_builder = QueryBuilder::<MySql>::new(
-            "
-			INSERT INTO osu_score (id, osu_user_id, map_id, mods, mode)
-			",
-        );
-
-        scores_query_builder.push_values(
-            &performance_information,
-            |mut b, (.., (score, score_id))| {
-                b.push_bind(score_id)
-                    .push_bind(osu_id)
-                    .push_bind(score.map_id)
-                    .push_bind(score.mods.bits())
-                    .push_bind(mode_bits);
-            },
-        );
+        let new = performance_information.len();
 
-        let base_pp_query = |to_insert: &str| {
-            format!("INSERT INTO {submit_mode}_performance (score_id, overall, {to_insert})")
-        };
+        // Only the best 100 scores would survive the retention anyways.
+        performance_information.sort_by(|(a, ..), (b, ..)| b.pp().total_cmp(&a.pp()));
+        performance_information.truncate(100);
 
-        let base_performance_query = match submit_mode {
-            SubmittableMode::Osu => base_pp_query("aim, speed, flashlight, accuracy"),
-            SubmittableMode::Taiko => base_pp_query("accuracy, difficulty"),
-            SubmittableMode::Mania => base_pp_query("difficulty"),
-        };
+        let kept =
+            store_performances(db, osu_id, submit_mode, source, &performance_information).await?;
 
-        let mut performance_query_builder = QueryBuilder::<MySql>::new(base_performance_query);
-
-        performance_query_builder.push_values(
-            &performance_information,
-            |mut b, (bonkers_performance, (.., score_id))| {
-                b.push_bind(score_id);
-
-                match bonkers_performance {
-                    BonkersferformanceAttributes::Osu(OsuPerformanceAttributes {
-                        pp,
-                        pp_acc,
-                        pp_aim,
-                        pp_flashlight,
-                        pp_speed,
-                        ..
-                    }) => b
-                        .push_bind(pp)
-                        .push_bind(pp_aim)
-                        .push_bind(pp_speed)
-                        .push_bind(pp_flashlight)
-                        .push_bind(pp_acc),
-                    BonkersferformanceAttributes::Taiko(TaikoPerformanceAttributes {
-                        pp,
-                        pp_acc,
-                        pp_difficulty,
-                        ..
-                    }) => b.push_bind(pp).push_bind(pp_acc).push_bind(pp_difficulty),
-                    BonkersferformanceAttributes::Mania(ManiaPerformanceAttributes {
-                        pp,
-                        pp_difficulty,
-                        ..
-                    }) => b.push_bind(pp).push_bind(pp_difficulty),
-                };
-            },
-        );
-
-        let mut tx = db.begin().await?;
-
-        scores_query_builder.build().execute(&mut *tx).await?;
-        performance_query_builder.build().execute(&mut *tx).await?;
-
-        sqlx::query!(
-            "
-			DELETE FROM osu_score
-			WHERE id NOT IN (
-				SELECT top_100.id
-				FROM (
-					SELECT id
-					FROM osu_score
-					WHERE osu_user_id = ? AND mode = ?
-					ORDER BY created_at DESC
-					LIMIT 100
-				) as top_100
-			) AND osu_user_id = ? AND mode = ?
-			",
-            &osu_id,
-            &osu_id,
-            &mode_bits,
-            &mode_bits
-        )
-        .execute(&mut *tx)
+        Ok(SubmittedScores { new, kept })
+    }
+}
+
+/// Fails for users that opted out, users that were never stored have not opted out.
+async fn ensure_opted_in(db: &MySqlPool, osu_id: u32) -> Result<(), SubmissionError> {
+    let opted_out = sqlx::query_scalar!("SELECT opted_out FROM osu_user WHERE id = ?", &osu_id)
+        .fetch_optional(db)
         .await?;
 
-        tx.commit().await?;
+    match opted_out {
+        Some(true) => Err(SubmissionError::OptedOut),
+        _ => Ok(()),
+    }
+}
 
-        locker_guard.unlock().await?;
+/// Removes the stored api top plays of the user that the api no longer lists in their top 100.
+async fn evict_unlisted_top_plays(
+    db: &MySqlPool,
+    osu_id: u32,
+    submit_mode: SubmittableMode,
+    top_plays: &[TopPlay],
+) -> Result<(), sqlx::Error> {
+    let listed_ids = top_plays
+        .iter()
+        .filter_map(|play| play.score.score_id)
+        .collect_vec();
+
+    if listed_ids.is_empty() {
+        return Ok(());
+    }
 
-        Ok(())
+    let mut query_builder =
+        QueryBuilder::<MySql>::new("DELETE FROM osu_score WHERE osu_user_id = ");
+
+    query_builder
+        .push_bind(osu_id)
+        .push(" AND mode = ")
+        .push_bind(GameMode::from(submit_mode) as i16)
+        .push(" AND source = 'api' AND id NOT IN (");
+
+    let mut ids = query_builder.separated(", ");
+
+    for id in listed_ids {
+        ids.push_bind(id);
     }
+
+    ids.push_unseparated(")");
+
+    query_builder.build().execute(db).await?;
+
+    Ok(())
+}
+
+/// The ids of the scores of the user that already have their performance stored for the mode.
+pub async fn stored_score_ids(
+    db: &MySqlPool,
+    osu_id: u32,
+    submit_mode: SubmittableMode,
+) -> Result<HashSet<u64>, sqlx::Error> {
+    #[derive(sqlx::FromRow)]
+    struct ExistingScore {
+        id: u64,
+    }
+
+    let rika_osu_scores: Vec<ExistingScore> = sqlx::query_as(&format!(
+        "
+		SELECT s.id FROM osu_score s
+		JOIN {submit_mode}_performance pp ON s.id = pp.score_id
+		WHERE s.osu_user_id = ?
+		"
+    ))
+    .bind(osu_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(rika_osu_scores.into_iter().map(|s| s.id).collect())
+}
+
+pub async fn calculate_performance(
+    beatmap_cache: &BeatmapCache,
+    mode: GameMode,
+    score: &SubmittableScore,
+) -> Result<Option<BonkersferformanceAttributes>, SubmissionError> {
+    let beatmap_file = beatmap_cache.get_beatmap_file(score.map_id).await?;
+    let beatmap_rosu = rosu_pp::Beatmap::from_bytes(&beatmap_file).await?;
+
+    macro_rules! calc {
+        ($mode:ident) => {
+            paste! {
+                [<$mode PP>]::new(&beatmap_rosu)
+            }
+            .mods(score.mods)
+            .n300(calc!(+count_300))
+            .n100(calc!(+count_100))
+            .n_misses(calc!(+count_miss))
+        };
+        (+$dep:ident) => {
+            score.$dep as usize
+        };
+    }
+
+    let performance_attributes = match mode {
+        GameMode::Osu => Some(
+            calc!(Osu)
+                .n50(calc!(+count_50))
+                .combo(calc!(+max_combo))
+                .calculate()
+                .into(),
+        ),
+        GameMode::Taiko => Some(calc!(Taiko).combo(calc!(+max_combo)).calculate().into()),
+        GameMode::Mania => Some(
+            calc!(Mania)
+                .n320(calc!(+count_geki))
+                .n200(calc!(+count_katu))
+                .calculate()
+                .into(),
+        ),
+        _ => None,
+    };
+
+    Ok(performance_attributes)
+}
+
+/// Stores scores alongside their performance, keeping only the best 100 scores of the user.
+/// Returns how many of the given scores are still stored after the retention.
+pub async fn store_performances(
+    db: &MySqlPool,
+    osu_id: u32,
+    submit_mode: SubmittableMode,
+    source: ScoreSource,
+    performance_information: &[(BonkersferformanceAttributes, SubmittableScore)],
+) -> Result<usize, SubmissionError> {
+    if performance_information.is_empty() {
+        return Ok(0);
+    }
+
+    let mode_bits = GameMode::from(submit_mode) as i16;
+
+    let mut scores_query_builder = QueryBuilder::<MySql>::new(
+        "
+		INSERT INTO osu_score (id, osu_user_id, map_id, mapper_id, mods, mode, source, stars)
+		",
+    );
+
+    scores_query_builder.push_values(performance_information, |mut b, (attributes, score)| {
+        b.push_bind(score.id)
+            .push_bind(osu_id)
+            .push_bind(score.map_id)
+            .push_bind(score.mapper_id)
+            .push_bind(score.mods)
+            .push_bind(mode_bits)
+            .push_bind(source.to_string())
+            .push_bind(attributes.stars() as f32);
+    });
+
+    let base_pp_query = |to_insert: &str| {
+        format!("INSERT INTO {submit_mode}_performance (score_id, overall, {to_insert})")
+    };
+
+    let base_performance_query = match submit_mode {
+        SubmittableMode::Osu => base_pp_query("aim, speed, flashlight, accuracy"),
+        SubmittableMode::Taiko => base_pp_query("accuracy, difficulty"),
+        SubmittableMode::Mania => base_pp_query("difficulty"),
+    };
+
+    let mut performance_query_builder = QueryBuilder::<MySql>::new(base_performance_query);
+
+    performance_query_builder.push_values(
+        performance_information,
+        |mut b, (bonkers_performance, score)| {
+            b.push_bind(score.id);
+
+            match bonkers_performance {
+                BonkersferformanceAttributes::Osu(OsuPerformanceAttributes {
+                    pp,
+                    pp_acc,
+                    pp_aim,
+                    pp_flashlight,
+                    pp_speed,
+                    ..
+                }) => b
+                    .push_bind(pp)
+                    .push_bind(pp_aim)
+                    .push_bind(pp_speed)
+                    .push_bind(pp_flashlight)
+                    .push_bind(pp_acc),
+                BonkersferformanceAttributes::Taiko(TaikoPerformanceAttributes {
+                    pp,
+                    pp_acc,
+                    pp_difficulty,
+                    ..
+                }) => b.push_bind(pp).push_bind(pp_acc).push_bind(pp_difficulty),
+                BonkersferformanceAttributes::Mania(ManiaPerformanceAttributes {
+                    pp,
+                    pp_difficulty,
+                    ..
+                }) => b.push_bind(pp).push_bind(pp_difficulty),
+            };
+        },
+    );
+
+    let mut tx = db.begin().await?;
+
+    scores_query_builder.build().execute(&mut *tx).await?;
+    performance_query_builder.build().execute(&mut *tx).await?;
+
+    // Api top plays are never evicted here, since the next submission would only calculate and
+    // offer them again, they are dropped once the api stops listing them instead.
+    sqlx::query(&format!(
+        "
+		DELETE FROM osu_score
+		WHERE id NOT IN (
+			SELECT top_100.id
+			FROM (
+				SELECT s.id
+				FROM osu_score s
+				JOIN {submit_mode}_performance pp ON s.id = pp.score_id
+				WHERE s.osu_user_id = ? AND s.mode = ?
+				ORDER BY pp.overall DESC
+				LIMIT 100
+			) as top_100
+		) AND osu_user_id = ? AND mode = ? AND source != 'api'
+		"
+    ))
+    .bind(osu_id)
+    .bind(mode_bits)
+    .bind(osu_id)
+    .bind(mode_bits)
+    .execute(&mut *tx)
+    .await?;
+
+    let mut kept_query_builder =
+        QueryBuilder::<MySql>::new("SELECT COUNT(*) FROM osu_score WHERE mode = ");
+
+    kept_query_builder.push_bind(mode_bits).push(" AND id IN (");
+
+    let mut kept_ids = kept_query_builder.separated(", ");
+
+    for (_, score) in performance_information {
+        kept_ids.push_bind(score.id);
+    }
+
+    kept_ids.push_unseparated(")");
+
+    let kept: i64 = kept_query_builder
+        .build_query_scalar()
+        .fetch_one(&mut *tx)
+        .await?;
+
+    sqlx::query!(
+        "
+        INSERT INTO osu_user_mode (osu_user_id, mode, last_submitted_at) VALUES (?, ?, NOW())
+        ON DUPLICATE KEY UPDATE last_submitted_at = NOW()
+        ",
+        &osu_id,
+        &mode_bits
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(kept as usize)
 }
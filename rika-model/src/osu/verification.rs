@@ -0,0 +1,141 @@
+use sqlx::{MySql, Pool};
+
+use super::link::{is_claimed_by_other, log_link_action, remove_link, LinkAction, LinkPolicy};
+
+/// How long a link code can be sent on Bancho before it expires.
+pub const LINK_CODE_MINUTES: u32 = 10;
+
+#[derive(thiserror::Error, Debug)]
+pub enum VerificationError {
+    #[error("This code does not exist or has expired.")]
+    InvalidCode,
+
+    #[error("This code was created for another osu! account.")]
+    WrongAccount(String),
+
+    #[error("This osu! account is already linked to another discord account.")]
+    AlreadyClaimed(String),
+
+    #[error(transparent)]
+    Sqlx(#[from] sqlx::Error),
+}
+
+/// Bancho replaces the spaces of usernames with underscores, and neither are case sensitive.
+fn irc_name(username: &str) -> String {
+    username.replace(' ', "_").to_lowercase()
+}
+
+/// Replaces any pending link of the discord user, clearing expired codes on the way.
+pub async fn create_link_code(
+    db: &Pool<MySql>,
+    code: &str,
+    discord_id: &str,
+    osu_id: u32,
+    username: &str,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "DELETE FROM link_verification WHERE discord_id = ? OR expires_at < NOW()",
+        discord_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        INSERT INTO link_verification (code, discord_id, osu_id, username, expires_at)
+        VALUES (?, ?, ?, ?, NOW() + INTERVAL ? MINUTE)
+        ",
+        code,
+        discord_id,
+        &osu_id,
+        username,
+        &LINK_CODE_MINUTES
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await
+}
+
+/// Confirms the pending link of a code sent by its osu! account, returning the linked username.
+pub async fn verify_link_code(
+    db: &Pool<MySql>,
+    policy: LinkPolicy,
+    code: &str,
+    sender: &str,
+) -> Result<String, VerificationError> {
+    let code = code.to_uppercase();
+
+    let pending = sqlx::query!(
+        "
+        SELECT discord_id, osu_id, username FROM link_verification
+        WHERE code = ? AND expires_at > NOW()
+        ",
+        &code
+    )
+    .fetch_optional(db)
+    .await?
+    .ok_or(VerificationError::InvalidCode)?;
+
+    if irc_name(&pending.username) != irc_name(sender) {
+        return Err(VerificationError::WrongAccount(pending.username));
+    }
+
+    // Checked again, someone else could have verified the account since the code was created.
+    if is_claimed_by_other(db, policy, pending.osu_id, &pending.discord_id).await? {
+        return Err(VerificationError::AlreadyClaimed(pending.username));
+    }
+
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "
+        INSERT INTO rika_user (discord_id, osu_id, verified_at)
+        VALUES (?, ?, NOW())
+        ON DUPLICATE KEY UPDATE
+            osu_id = VALUES(osu_id),
+            verified_at = VALUES(verified_at)
+        ",
+        &pending.discord_id,
+        &pending.osu_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!("DELETE FROM link_verification WHERE code = ?", &code)
+        .execute(&mut *tx)
+        .await?;
+
+    // The verified link displaces whoever only claimed the account.
+    if policy == LinkPolicy::Exclusive {
+        let displaced = sqlx::query_scalar!(
+            "
+            SELECT discord_id FROM rika_user
+            WHERE osu_id = ? AND discord_id != ? AND verified_at IS NULL
+            FOR UPDATE
+            ",
+            &pending.osu_id,
+            &pending.discord_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for discord_id in displaced.iter().flatten() {
+            remove_link(&mut *tx, discord_id, pending.osu_id).await?;
+        }
+    }
+
+    log_link_action(
+        &mut *tx,
+        &pending.discord_id,
+        pending.osu_id,
+        LinkAction::Link,
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(pending.username)
+}
@@ -0,0 +1,104 @@
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use poise::serenity_prelude::{ChannelId, GuildId};
+use rosu_v2::prelude::GameMode;
+use sqlx::MySqlPool;
+use tokio::sync::RwLock;
+
+use crate::i18n::RikaLocale;
+
+/// The settings of a guild, a `None` setting falls back to the bot's default.
+#[derive(Clone, Default, Debug)]
+pub struct GuildConfig {
+    pub default_mode: Option<GameMode>,
+    pub locale: Option<RikaLocale>,
+    pub announcement_channel: Option<ChannelId>,
+    pub recommend_range: Option<f32>,
+    /// Full command names like `osu recommend`, which also turn off their subcommands.
+    pub disabled_commands: Vec<String>,
+}
+
+impl GuildConfig {
+    pub async fn load(db: &MySqlPool, guild_id: GuildId) -> Result<Self, sqlx::Error> {
+        let config = sqlx::query!(
+            "
+            SELECT default_mode, locale, announcement_channel_id, recommend_range
+            FROM guild_config WHERE guild_id = ?
+            ",
+            &guild_id.0
+        )
+        .fetch_optional(db)
+        .await?;
+
+        let disabled_commands = sqlx::query_scalar!(
+            "SELECT command FROM guild_disabled_command WHERE guild_id = ? ORDER BY command",
+            &guild_id.0
+        )
+        .fetch_all(db)
+        .await?;
+
+        let Some(config) = config else {
+            return Ok(Self {
+                disabled_commands,
+                ..Default::default()
+            });
+        };
+
+        Ok(Self {
+            default_mode: config.default_mode.map(|mode| GameMode::from(mode as u8)),
+            locale: config
+                .locale
+                .and_then(|locale| RikaLocale::from_str(&locale).ok()),
+            announcement_channel: config.announcement_channel_id.map(ChannelId),
+            recommend_range: config.recommend_range,
+            disabled_commands,
+        })
+    }
+
+    /// Whether the command or one of its parents is turned off.
+    pub fn is_disabled(&self, qualified_name: &str) -> bool {
+        self.disabled_commands.iter().any(|disabled| {
+            qualified_name == disabled
+                || qualified_name
+                    .strip_prefix(disabled.as_str())
+                    .is_some_and(|rest| rest.starts_with(' '))
+        })
+    }
+}
+
+/// Guild configs read so far, kept until they are changed through `/config`.
+///
+/// Lookups only wait on a config being stored, and the locale of a guild can be read outside of
+/// async code as long as nothing is being stored right then.
+#[derive(Default)]
+pub struct GuildConfigCache {
+    configs: RwLock<HashMap<GuildId, Arc<GuildConfig>>>,
+}
+
+impl GuildConfigCache {
+    pub async fn get(
+        &self,
+        db: &MySqlPool,
+        guild_id: GuildId,
+    ) -> Result<Arc<GuildConfig>, sqlx::Error> {
+        if let Some(config) = self.configs.read().await.get(&guild_id) {
+            return Ok(config.clone());
+        }
+
+        let config = Arc::new(GuildConfig::load(db, guild_id).await?);
+
+        self.configs.write().await.insert(guild_id, config.clone());
+
+        Ok(config)
+    }
+
+    /// The config of the guild, if it was read since it last changed and is not being written to.
+    pub fn cached(&self, guild_id: GuildId) -> Option<Arc<GuildConfig>> {
+        self.configs.try_read().ok()?.get(&guild_id).cloned()
+    }
+
+    /// Makes the next lookup read the config of the guild again.
+    pub async fn invalidate(&self, guild_id: GuildId) {
+        self.configs.write().await.remove(&guild_id);
+    }
+}
@@ -1,9 +1,12 @@
+pub mod guild_config;
+
 use std::sync::Arc;
 
 use derive_more::From;
+use guild_config::{GuildConfig, GuildConfigCache};
 use id_locked::IDLockerError;
 use lexicon::Localizer;
-use poise::serenity_prelude;
+use poise::serenity_prelude::{self, GuildId};
 use roricon::RoriconMetaTrait;
 use serde::Deserialize;
 
@@ -13,16 +16,39 @@ use crate::{
     SharedRika,
 };
 
+fn default_recommendation_cooldown_hours() -> u32 {
+    24 * 7
+}
+
+fn default_top_play_check_minutes() -> u32 {
+    30
+}
+
 #[derive(Deserialize)]
 pub struct Config {
     pub bot_token: String,
-    pub scraped_country: String,
     pub development_guild: Option<u64>,
+    /// `BANCHO_USERNAME` and `BANCHO_PREFIX`, the same variables rika-bancho already requires, so
+    /// `/osu link` can tell where to send the verification code.
+    pub bancho_username: String,
+    pub bancho_prefix: String,
+    #[serde(default = "default_recommendation_cooldown_hours")]
+    pub recommendation_cooldown_hours: u32,
+    #[serde(default = "default_top_play_check_minutes")]
+    pub top_play_check_minutes: u32,
 }
 
 pub struct Data {
     pub config: Config,
     pub shared: Arc<SharedRika>,
+    pub guild_configs: GuildConfigCache,
+}
+
+impl Data {
+    /// The settings of the guild, read from the database only once until they change.
+    pub async fn guild_config(&self, guild_id: GuildId) -> Result<Arc<GuildConfig>, sqlx::Error> {
+        self.guild_configs.get(&self.shared.db, guild_id).await
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -30,6 +56,9 @@ pub enum OsuError {
     #[error("You must link your account to use this command.")]
     NotLinked,
 
+    #[error("You must verify your link to use this command. Try `/osu link` again.")]
+    NotVerified,
+
     #[error("You must submit some scores before using this command. Try `/osu submit`")]
     RequiresSubmission,
 
@@ -73,4 +102,11 @@ impl<'a> RoriconMetaTrait<RikaLocale, RikaLocalizer> for Context<'a> {
     fn locales(&self) -> &Localizer<RikaLocale, RikaLocalizer> {
         &self.data().shared.locales
     }
+
+    // The guild config is cached by the command check, before any command runs.
+    fn locale_override(&self) -> Option<RikaLocale> {
+        let guild_id = self.guild_id()?;
+
+        self.data().guild_configs.cached(guild_id)?.locale
+    }
 }
@@ -0,0 +1,118 @@
+use serde::Deserialize;
+
+use crate::osu::submit::SubmittableMode;
+
+fn default_modes() -> Vec<SubmittableMode> {
+    vec![
+        SubmittableMode::Osu,
+        SubmittableMode::Taiko,
+        SubmittableMode::Mania,
+    ]
+}
+
+fn default_first_page() -> u32 {
+    1
+}
+
+fn default_last_page() -> u32 {
+    100
+}
+
+fn default_user_delay_ms() -> u64 {
+    1000
+}
+
+fn default_retries() -> u32 {
+    3
+}
+
+fn default_recheck_hours() -> u32 {
+    24
+}
+
+fn default_rank_change_threshold() -> f32 {
+    0.1
+}
+
+fn default_pass_delay_minutes() -> u64 {
+    10
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ScraperConfigError {
+    #[error("SCRAPER_FIRST_PAGE starts at 1.")]
+    FirstPageZero,
+
+    #[error("SCRAPER_FIRST_PAGE is past SCRAPER_LAST_PAGE.")]
+    EmptyPageRange,
+
+    #[error("SCRAPER_MODES has no mode to scrape.")]
+    NoModes,
+
+    #[error("SCRAPER_RETRIES has to be at least 1.")]
+    NoRetries,
+}
+
+/// Read from the `SCRAPER_` prefixed environment variables, lists are comma separated.
+#[derive(Deserialize, Clone)]
+pub struct ScraperConfig {
+    /// Country codes whose rankings are scraped, the global ranking is scraped when empty.
+    #[serde(default)]
+    pub countries: Vec<String>,
+    #[serde(default = "default_modes")]
+    pub modes: Vec<SubmittableMode>,
+    #[serde(default = "default_first_page")]
+    pub first_page: u32,
+    /// Inclusive, the osu! api does not go past page 200.
+    #[serde(default = "default_last_page")]
+    pub last_page: u32,
+    #[serde(default = "default_user_delay_ms")]
+    pub user_delay_ms: u64,
+    /// How many times a ranking page or a user is attempted before being skipped.
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+    /// How long a checked user is left alone, unless their rank moves enough.
+    #[serde(default = "default_recheck_hours")]
+    pub recheck_hours: u32,
+    /// The fraction of their previous position a user has to move by to be checked again early.
+    #[serde(default = "default_rank_change_threshold")]
+    pub rank_change_threshold: f32,
+    /// How long the scraper rests after a pass before starting the next one.
+    #[serde(default = "default_pass_delay_minutes")]
+    pub pass_delay_minutes: u64,
+}
+
+impl ScraperConfig {
+    pub fn validate(&self) -> Result<(), ScraperConfigError> {
+        if self.first_page == 0 {
+            return Err(ScraperConfigError::FirstPageZero);
+        }
+
+        if self.first_page > self.last_page {
+            return Err(ScraperConfigError::EmptyPageRange);
+        }
+
+        if self.modes.is_empty() {
+            return Err(ScraperConfigError::NoModes);
+        }
+
+        if self.retries == 0 {
+            return Err(ScraperConfigError::NoRetries);
+        }
+
+        Ok(())
+    }
+
+    /// Every ranking scraped on a pass, in order, `None` being the global ranking.
+    pub fn targets(&self) -> Vec<(Option<String>, SubmittableMode)> {
+        let countries = match self.countries.is_empty() {
+            true => vec![None],
+            false => self.countries.iter().cloned().map(Some).collect(),
+        };
+
+        countries
+            .into_iter()
+            .flat_map(|country| self.modes.iter().map(move |mode| (country.clone(), *mode)))
+            .collect()
+    }
+}
@@ -0,0 +1,65 @@
+use rosu_v2::prelude::GameMode;
+use sqlx::{MySql, Pool};
+
+use crate::osu::submit::SubmittableMode;
+
+/// There is only ever one scraper, so its cursor is always the same row.
+const CURSOR_ID: u8 = 1;
+
+/// The next ranking page the scraper collects, so a restart resumes from it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ScraperCursor {
+    pub country: Option<String>,
+    pub mode: SubmittableMode,
+    pub page: u32,
+}
+
+impl ScraperCursor {
+    pub async fn load(db: &Pool<MySql>) -> Result<Option<Self>, sqlx::Error> {
+        let cursor = sqlx::query!(
+            "SELECT country, mode, page FROM scraper_cursor WHERE id = ?",
+            &CURSOR_ID
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(cursor.and_then(|cursor| {
+            let mode = SubmittableMode::try_from(GameMode::from(cursor.mode as u8)).ok()?;
+
+            Some(Self {
+                country: Some(cursor.country).filter(|country| !country.is_empty()),
+                mode,
+                page: cursor.page,
+            })
+        }))
+    }
+
+    pub async fn save(&self, db: &Pool<MySql>) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO scraper_cursor (id, country, mode, page)
+            VALUES (?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                country = VALUES(country),
+                mode = VALUES(mode),
+                page = VALUES(page)
+            ",
+            &CURSOR_ID,
+            &self.country.as_deref().unwrap_or_default(),
+            &(GameMode::from(self.mode) as i16),
+            &self.page
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn clear(db: &Pool<MySql>) -> Result<(), sqlx::Error> {
+        sqlx::query!("DELETE FROM scraper_cursor WHERE id = ?", &CURSOR_ID)
+            .execute(db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::{Notify, RwLock};
+
+use crate::osu::submit::SubmittableMode;
+
+use super::config::ScraperConfig;
+
+/// What the scraper is doing right now, reset at the start of every pass.
+#[derive(Clone, Default)]
+pub struct ScraperStatus {
+    pub country: Option<String>,
+    pub mode: Option<SubmittableMode>,
+    pub page: Option<u32>,
+    pub users_processed: u32,
+    pub errors: u32,
+    /// Ranking pages and due users handled this pass, more steps are known once a ranking is
+    /// collected and its due users are found.
+    pub steps_done: u32,
+    pub steps_total: u32,
+    pub pass_started_at: Option<Instant>,
+}
+
+impl ScraperStatus {
+    /// Extrapolates how long the rest of the pass takes from how long the steps so far took.
+    pub fn eta(&self) -> Option<Duration> {
+        let started_at = self.pass_started_at?;
+
+        if self.steps_done == 0 {
+            return None;
+        }
+
+        let per_step = started_at.elapsed() / self.steps_done;
+
+        Some(per_step * self.steps_total.saturating_sub(self.steps_done))
+    }
+}
+
+/// Shared between the scraper task and whoever controls it, changes to the config apply from the
+/// next pass and last until a restart.
+pub struct ScraperHandle {
+    config: RwLock<ScraperConfig>,
+    status: RwLock<ScraperStatus>,
+    paused: AtomicBool,
+    resumed: Notify,
+}
+
+impl ScraperHandle {
+    pub fn new(config: ScraperConfig) -> Self {
+        Self {
+            config: RwLock::new(config),
+            status: RwLock::new(ScraperStatus::default()),
+            paused: AtomicBool::new(false),
+            resumed: Notify::new(),
+        }
+    }
+
+    pub async fn config(&self) -> ScraperConfig {
+        self.config.read().await.clone()
+    }
+
+    pub async fn set_countries(&self, countries: Vec<String>) {
+        self.config.write().await.countries = countries;
+    }
+
+    pub async fn set_modes(&self, modes: Vec<SubmittableMode>) {
+        self.config.write().await.modes = modes;
+    }
+
+    pub async fn status(&self) -> ScraperStatus {
+        self.status.read().await.clone()
+    }
+
+    pub(crate) async fn update_status(&self, update: impl FnOnce(&mut ScraperStatus)) {
+        update(&mut *self.status.write().await);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether the scraper was running before.
+    pub fn pause(&self) -> bool {
+        !self.paused.swap(true, Ordering::SeqCst)
+    }
+
+    /// Returns whether the scraper was paused before.
+    pub fn resume(&self) -> bool {
+        let was_paused = self.paused.swap(false, Ordering::SeqCst);
+        self.resumed.notify_waiters();
+
+        was_paused
+    }
+
+    pub(crate) async fn wait_while_paused(&self) {
+        loop {
+            // Created before checking, so a resume in between is not missed.
+            let resumed = self.resumed.notified();
+
+            if !self.is_paused() {
+                return;
+            }
+
+            resumed.await;
+        }
+    }
+}
@@ -0,0 +1,377 @@
+pub mod config;
+pub mod cursor;
+pub mod handle;
+
+use std::{
+    fmt::Debug,
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use log::{error, info, warn};
+use rosu_v2::prelude::{GameMode, Rankings};
+use sqlx::{MySql, QueryBuilder};
+use tokio::time::sleep;
+
+use crate::{
+    osu::{
+        skills::refresh_skill_ranking,
+        submit::{ScoreSubmitter, SubmissionError, SubmittableMode},
+    },
+    SharedRika,
+};
+
+use self::{config::ScraperConfig, cursor::ScraperCursor, handle::ScraperStatus};
+
+/// How many users the osu! api returns on each ranking page.
+const RANKING_PAGE_SIZE: u32 = 50;
+
+/// Walks the configured rankings submitting the scores of the users on them that are due a check,
+/// forever.
+pub struct Scraper {
+    data: Arc<SharedRika>,
+    config: ScraperConfig,
+}
+
+impl Scraper {
+    /// Controlled through [`SharedRika::scraper`], the config is read again before every pass.
+    pub async fn run(data: Arc<SharedRika>) {
+        loop {
+            let scraper = Self {
+                config: data.scraper.config().await,
+                data: data.clone(),
+            };
+
+            // Idle passes leave the ranking be, scores submitted elsewhere meanwhile are ranked
+            // on the next pass that submits any.
+            if scraper.scrape_pass().await > 0 {
+                match refresh_skill_ranking(&data.db).await {
+                    Ok(..) => info!("Refreshed the skill ranking"),
+                    Err(e) => scraper.failed(e).await,
+                }
+            }
+
+            sleep(Duration::from_secs(scraper.config.pass_delay_minutes * 60)).await;
+        }
+    }
+
+    async fn count_error(&self) {
+        self.data
+            .scraper
+            .update_status(|status| status.errors += 1)
+            .await;
+    }
+
+    async fn failed(&self, e: impl Debug) {
+        error!("{e:?}");
+        self.count_error().await;
+    }
+
+    /// Runs the task until it succeeds or runs out of retries, waiting longer after every failure.
+    async fn retrying<T, E: Debug, F: Future<Output = Result<T, E>>>(
+        &self,
+        what: &str,
+        task: impl Fn() -> F,
+    ) -> Option<T> {
+        for attempt in 1..=self.config.retries {
+            match task().await {
+                Ok(value) => return Some(value),
+                Err(e) => {
+                    warn!("Attempt {attempt} at {what} failed: {e:?}");
+                    sleep(Duration::from_millis(
+                        self.config.user_delay_ms * attempt as u64,
+                    ))
+                    .await;
+                }
+            }
+        }
+
+        error!("Gave up on {what}");
+        self.count_error().await;
+
+        None
+    }
+
+    async fn fetch_ranking(
+        &self,
+        country: &Option<String>,
+        mode: SubmittableMode,
+        page: u32,
+    ) -> Result<Rankings, rosu_v2::error::OsuError> {
+        let mut rankings = self.data.rosu.performance_rankings(mode.into()).page(page);
+
+        if let Some(country) = country {
+            rankings = rankings.country(country.as_str());
+        }
+
+        rankings.await
+    }
+
+    /// Stores the profiles and positions of the users on a ranking page, keeping track of how much
+    /// each of them moved since the last pass.
+    async fn store_ranking(
+        &self,
+        country: &Option<String>,
+        mode: SubmittableMode,
+        page: u32,
+        ranking: &Rankings,
+    ) -> Result<(), sqlx::Error> {
+        let db = &self.data.db;
+        let first_position = (page - 1) * RANKING_PAGE_SIZE + 1;
+
+        let mut tx = db.begin().await?;
+
+        let mut profiles_query =
+            QueryBuilder::<MySql>::new("INSERT INTO osu_user (id, username, country_code)");
+
+        profiles_query.push_values(&ranking.ranking, |mut b, user| {
+            b.push_bind(user.user_id)
+                .push_bind(user.username.as_str())
+                .push_bind(user.country_code.as_str());
+        });
+
+        profiles_query.push(
+            "
+            ON DUPLICATE KEY UPDATE
+                username = VALUES(username),
+                country_code = VALUES(country_code)
+            ",
+        );
+
+        profiles_query.build().execute(&mut *tx).await?;
+
+        let mut ranks_query = QueryBuilder::<MySql>::new(
+            "INSERT INTO scraper_rank (country, mode, osu_user_id, position)",
+        );
+
+        ranks_query.push_values(ranking.ranking.iter().enumerate(), |mut b, (i, user)| {
+            b.push_bind(country.as_deref().unwrap_or_default())
+                .push_bind(GameMode::from(mode) as i16)
+                .push_bind(user.user_id)
+                .push_bind(first_position + i as u32);
+        });
+
+        // The assignments run in order, so the previous position is taken before it is replaced.
+        ranks_query.push(
+            "
+            ON DUPLICATE KEY UPDATE
+                previous_position = IF(position = VALUES(position), previous_position, position),
+                changed_at = IF(position = VALUES(position), changed_at, NOW()),
+                position = VALUES(position)
+            ",
+        );
+
+        ranks_query.build().execute(&mut *tx).await?;
+
+        tx.commit().await
+    }
+
+    /// The users of a ranking that are due a check: the ones never checked first, then the ones
+    /// whose position moved a lot since their last check, then the ones checked the longest ago.
+    async fn due_users(
+        &self,
+        country: &Option<String>,
+        mode: SubmittableMode,
+    ) -> Result<Vec<u32>, sqlx::Error> {
+        let first_position = (self.config.first_page - 1) * RANKING_PAGE_SIZE + 1;
+        let last_position = self.config.last_page * RANKING_PAGE_SIZE;
+
+        sqlx::query_scalar(
+            "
+            SELECT id FROM (
+                SELECT
+                    u.id,
+                    m.last_checked_at,
+                    r.changed_at > m.last_checked_at
+                        AND ABS(CAST(r.position AS SIGNED) - CAST(r.previous_position AS SIGNED))
+                            >= r.previous_position * ? AS moved
+                FROM scraper_rank r
+                JOIN osu_user u ON u.id = r.osu_user_id
+                LEFT JOIN osu_user_mode m ON m.osu_user_id = r.osu_user_id AND m.mode = r.mode
+                WHERE r.country = ? AND r.mode = ? AND r.position BETWEEN ? AND ?
+                    AND NOT u.opted_out
+            ) candidates
+            WHERE last_checked_at IS NULL
+                OR last_checked_at < NOW() - INTERVAL ? HOUR
+                OR moved
+            ORDER BY last_checked_at IS NULL DESC, moved DESC, last_checked_at ASC
+            ",
+        )
+        .bind(self.config.rank_change_threshold)
+        .bind(country.as_deref().unwrap_or_default())
+        .bind(GameMode::from(mode) as i16)
+        .bind(first_position)
+        .bind(last_position)
+        .bind(self.config.recheck_hours)
+        .fetch_all(&self.data.db)
+        .await
+    }
+
+    async fn scrape_user(
+        &self,
+        osu_id: u32,
+        mode: SubmittableMode,
+    ) -> Result<usize, SubmissionError> {
+        ScoreSubmitter::begin_submission(&self.data.score_submitter)
+            .0
+            .submit_scores(osu_id, mode.into())
+            .await
+    }
+
+    /// Scrapes every configured ranking once, starting from the saved cursor if there is one,
+    /// returning how many scores were submitted.
+    ///
+    /// Each ranking is collected first, then only the users due a check get their scores submitted.
+    async fn scrape_pass(&self) -> usize {
+        let db = &self.data.db;
+        let handle = &self.data.scraper;
+        let targets = self.config.targets();
+
+        let cursor = match ScraperCursor::load(db).await {
+            Ok(cursor) => cursor,
+            Err(e) => {
+                self.failed(e).await;
+                None
+            }
+        };
+
+        let (first_target, resumed_page) = cursor
+            .and_then(|cursor| {
+                let target = targets.iter().position(|(country, mode)| {
+                    *country == cursor.country && *mode == cursor.mode
+                })?;
+
+                Some((target, cursor.page))
+            })
+            .unwrap_or((0, self.config.first_page));
+
+        let pages_per_target = (self.config.last_page + 1).saturating_sub(self.config.first_page);
+
+        handle
+            .update_status(|status| {
+                *status = ScraperStatus {
+                    steps_done: first_target as u32 * pages_per_target
+                        + resumed_page.saturating_sub(self.config.first_page),
+                    steps_total: targets.len() as u32 * pages_per_target,
+                    pass_started_at: Some(Instant::now()),
+                    ..Default::default()
+                }
+            })
+            .await;
+
+        let mut submitted_total = 0;
+
+        for (target, (country, mode)) in targets.iter().enumerate().skip(first_target) {
+            let ranking_name = format!(
+                "the {mode} ranking of {}",
+                country.as_deref().unwrap_or("the world")
+            );
+
+            let first_page = match target == first_target {
+                true => resumed_page.max(self.config.first_page),
+                false => self.config.first_page,
+            };
+
+            for page in first_page..=self.config.last_page {
+                handle.wait_while_paused().await;
+
+                handle
+                    .update_status(|status| {
+                        status.country = country.clone();
+                        status.mode = Some(*mode);
+                        status.page = Some(page);
+                        status.steps_done += 1;
+                    })
+                    .await;
+
+                let page_name = format!("page {page} of {ranking_name}");
+
+                let Some(ranking) = self
+                    .retrying(&page_name, || self.fetch_ranking(country, *mode, page))
+                    .await
+                else {
+                    continue;
+                };
+
+                if ranking.ranking.is_empty() {
+                    // The pages past the end of the ranking will not be walked either.
+                    let skipped = self.config.last_page - page;
+                    handle
+                        .update_status(|status| {
+                            status.steps_total = status.steps_total.saturating_sub(skipped)
+                        })
+                        .await;
+
+                    break;
+                }
+
+                if let Err(e) = self.store_ranking(country, *mode, page, &ranking).await {
+                    self.failed(e).await;
+                }
+
+                let cursor = ScraperCursor {
+                    country: country.clone(),
+                    mode: *mode,
+                    page: page + 1,
+                };
+
+                if let Err(e) = cursor.save(db).await {
+                    self.failed(e).await;
+                }
+
+                sleep(Duration::from_millis(self.config.user_delay_ms)).await;
+            }
+
+            let due_users = match self.due_users(country, *mode).await {
+                Ok(due_users) => due_users,
+                Err(e) => {
+                    self.failed(e).await;
+                    continue;
+                }
+            };
+
+            handle
+                .update_status(|status| status.steps_total += due_users.len() as u32)
+                .await;
+
+            info!(
+                "{} users of {ranking_name} are due a check",
+                due_users.len()
+            );
+
+            for osu_id in due_users {
+                handle.wait_while_paused().await;
+
+                let user_name = format!("user {osu_id} of {ranking_name}");
+
+                match self
+                    .retrying(&user_name, || self.scrape_user(osu_id, *mode))
+                    .await
+                {
+                    Some(0) => info!("No new top plays for {user_name}"),
+                    Some(submitted) => {
+                        info!("Submitted {submitted} new scores for {user_name}");
+                        submitted_total += submitted;
+                    }
+                    None => {}
+                }
+
+                handle
+                    .update_status(|status| {
+                        status.users_processed += 1;
+                        status.steps_done += 1;
+                    })
+                    .await;
+
+                sleep(Duration::from_millis(self.config.user_delay_ms)).await;
+            }
+        }
+
+        if let Err(e) = ScraperCursor::clear(db).await {
+            self.failed(e).await;
+        }
+
+        submitted_total
+    }
+}
@@ -0,0 +1,89 @@
+pub mod settings;
+pub mod show;
+pub mod toggle;
+
+use anyhow::anyhow;
+use lexicon::t_prefix;
+use poise::{command, serenity_prelude::GuildId, ChoiceParameter};
+use rika_model::{i18n::RikaLocale, rika_cord};
+use roricon::RoriconTrait;
+use settings::{announcement, locale, mode, range};
+use show::show;
+use toggle::{disable, enable};
+
+use crate::{
+    commands::{osu::remember_guild_member, CommandReturn},
+    utils::markdown::mono,
+};
+
+/// Changes how the bot behaves in this server (Manage Server)
+#[command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    default_member_permissions = "MANAGE_GUILD",
+    subcommands("show", "mode", "locale", "announcement", "range", "disable", "enable")
+)]
+pub async fn config(_ctx: rika_cord::Context<'_>) -> CommandReturn {
+    Ok(())
+}
+
+#[derive(ChoiceParameter, Clone, Copy)]
+pub enum ConfigLocale {
+    #[name = "English"]
+    English,
+
+    #[name = "Português (Brasil)"]
+    BrazilianPortuguese,
+}
+
+impl From<ConfigLocale> for RikaLocale {
+    fn from(val: ConfigLocale) -> Self {
+        match val {
+            ConfigLocale::English => RikaLocale::UnitedStatesEnglish,
+            ConfigLocale::BrazilianPortuguese => RikaLocale::BrazilianPortuguese,
+        }
+    }
+}
+
+impl From<RikaLocale> for ConfigLocale {
+    fn from(val: RikaLocale) -> Self {
+        match val {
+            RikaLocale::UnitedStatesEnglish => ConfigLocale::English,
+            RikaLocale::BrazilianPortuguese => ConfigLocale::BrazilianPortuguese,
+        }
+    }
+}
+
+/// Reads the config of the guild again after it changed.
+pub async fn refresh(ctx: &rika_cord::Context<'_>, guild_id: GuildId) -> Result<(), sqlx::Error> {
+    ctx.data().guild_configs.invalidate(guild_id).await;
+    ctx.data().guild_config(guild_id).await?;
+
+    Ok(())
+}
+
+/// Stops the commands a guild turned off, and caches the guild config before any command runs.
+///
+/// The author is remembered as a member of the guild on the way, for the guild leaderboards.
+pub async fn command_check(ctx: rika_cord::Context<'_>) -> Result<bool, rika_cord::Error> {
+    let Some(guild_id) = ctx.guild_id() else {
+        return Ok(true);
+    };
+
+    remember_guild_member(&ctx.data().shared.db, guild_id, ctx.author().id).await?;
+
+    let config = ctx.data().guild_config(guild_id).await?;
+    let qualified_name = &ctx.command().qualified_name;
+
+    if config.is_disabled(qualified_name) {
+        let i18n = ctx.i18n();
+        t_prefix!($, i18n.config);
+
+        return Err(anyhow!(
+            t!(command_disabled).r(mono(format!("/{qualified_name}")))
+        ))?;
+    }
+
+    Ok(true)
+}
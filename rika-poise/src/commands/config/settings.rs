@@ -0,0 +1,184 @@
+use lexicon::t_prefix;
+use poise::serenity_prelude::GuildChannel;
+use rika_model::{i18n::RikaLocale, rika_cord, SharedRika};
+use roricon::RoriconTrait;
+
+use super::{refresh, ConfigLocale};
+use crate::{
+    commands::{
+        osu::{recommend::DEFAULT_RECOMMEND_RANGE, OsuMode, RikaOsuContext},
+        CommandReturn,
+    },
+    utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
+};
+
+/// Sets the mode osu! commands use when none is given
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn mode(
+    ctx: rika_cord::Context<'_>,
+    #[description = "Goes back to osu! if empty"] mode: Option<OsuMode>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.config);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let guild_id = ctx.guild_id().ok_or(rika_cord::Error::Fallthrough)?;
+
+    if let Some(mode) = mode {
+        mode.submittable()?;
+    }
+
+    sqlx::query!(
+        "
+        INSERT INTO guild_config (guild_id, default_mode)
+        VALUES (?, ?)
+        ON DUPLICATE KEY UPDATE default_mode = VALUES(default_mode)
+        ",
+        &guild_id.0,
+        &mode.map(|mode| mode as i16)
+    )
+    .execute(db)
+    .await?;
+
+    refresh(&ctx, guild_id).await?;
+
+    let reply = match mode {
+        Some(mode) => t!(mode_set).r(mono(mode.to_string())),
+        None => t!(mode_reset).clone(),
+    };
+
+    ctx.say(cool_text(RikaMoji::Ok, &reply)).await?;
+
+    Ok(())
+}
+
+/// Sets the language the bot replies with, for everyone in the server
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn locale(
+    ctx: rika_cord::Context<'_>,
+    #[description = "Replies in the language of each user if empty"] locale: Option<ConfigLocale>,
+) -> CommandReturn {
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let guild_id = ctx.guild_id().ok_or(rika_cord::Error::Fallthrough)?;
+
+    sqlx::query!(
+        "
+        INSERT INTO guild_config (guild_id, locale)
+        VALUES (?, ?)
+        ON DUPLICATE KEY UPDATE locale = VALUES(locale)
+        ",
+        &guild_id.0,
+        &locale.map(|locale| RikaLocale::from(locale).to_string())
+    )
+    .execute(db)
+    .await?;
+
+    refresh(&ctx, guild_id).await?;
+
+    // Read after the refresh, so the reply is already in the new language.
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.config);
+
+    let reply = match locale {
+        Some(locale) => t!(locale_set).r(locale.to_string()),
+        None => t!(locale_reset).clone(),
+    };
+
+    ctx.say(cool_text(RikaMoji::Ok, &reply)).await?;
+
+    Ok(())
+}
+
+/// Posts the new top plays of linked members in a channel, in the server's default mode
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn announcement(
+    ctx: rika_cord::Context<'_>,
+    #[description = "Where new top plays are posted, turns announcements off if empty"]
+    #[channel_types("Text")]
+    channel: Option<GuildChannel>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.config);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let guild_id = ctx.guild_id().ok_or(rika_cord::Error::Fallthrough)?;
+
+    // Picking another channel starts over, so older plays are not posted all at once there.
+    // The assignments run in order, so the old channel is compared before it is replaced.
+    sqlx::query!(
+        "
+        INSERT INTO guild_config (guild_id, announcement_channel_id, announcement_since)
+        VALUES (?, ?, IF(? IS NULL, NULL, NOW()))
+        ON DUPLICATE KEY UPDATE
+            announcement_since = IF(
+                announcement_channel_id <=> VALUES(announcement_channel_id),
+                announcement_since,
+                VALUES(announcement_since)
+            ),
+            announcement_channel_id = VALUES(announcement_channel_id)
+        ",
+        &guild_id.0,
+        &channel.as_ref().map(|channel| channel.id.0),
+        &channel.as_ref().map(|channel| channel.id.0)
+    )
+    .execute(db)
+    .await?;
+
+    refresh(&ctx, guild_id).await?;
+
+    let reply = match channel {
+        Some(channel) => {
+            let mode = ctx.mode_or_default(None).await?;
+
+            t!(announcement_set).r((channel.to_string(), mode.to_string()))
+        }
+        None => t!(announcement_off).clone(),
+    };
+
+    ctx.say(cool_text(RikaMoji::Ok, &reply)).await?;
+
+    Ok(())
+}
+
+/// Sets how far from your skills recommendations may be when no range is given
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn range(
+    ctx: rika_cord::Context<'_>,
+    #[description = "Goes back to the bot's default if empty"]
+    #[min = 0.05]
+    #[max = 2.0]
+    range: Option<f32>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.config);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let guild_id = ctx.guild_id().ok_or(rika_cord::Error::Fallthrough)?;
+
+    sqlx::query!(
+        "
+        INSERT INTO guild_config (guild_id, recommend_range)
+        VALUES (?, ?)
+        ON DUPLICATE KEY UPDATE recommend_range = VALUES(recommend_range)
+        ",
+        &guild_id.0,
+        &range
+    )
+    .execute(db)
+    .await?;
+
+    refresh(&ctx, guild_id).await?;
+
+    let reply = match range {
+        Some(range) => t!(range_set).r(mono(range.to_string())),
+        None => t!(range_reset).r(mono(DEFAULT_RECOMMEND_RANGE.to_string())),
+    };
+
+    ctx.say(cool_text(RikaMoji::Ok, &reply)).await?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use itertools::Itertools;
+use lexicon::t_prefix;
+use poise::serenity_prelude::{Color, Mentionable};
+use rika_model::rika_cord;
+use roricon::RoriconTrait;
+
+use super::ConfigLocale;
+use crate::{
+    commands::{
+        osu::{recommend::DEFAULT_RECOMMEND_RANGE, OsuMode},
+        CommandReturn,
+    },
+    utils::{emojis::RikaMoji, markdown::mono},
+};
+
+/// Shows how the bot is set up in this server
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn show(ctx: rika_cord::Context<'_>) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.config.show);
+
+    let guild_id = ctx.guild_id().ok_or(rika_cord::Error::Fallthrough)?;
+    let config = ctx.data().guild_config(guild_id).await?;
+
+    let mode = mono(OsuMode::from(config.default_mode.unwrap_or_default()).to_string());
+
+    let locale = config
+        .locale
+        .map(|locale| ConfigLocale::from(locale).to_string())
+        .unwrap_or_else(|| t!(per_user).clone());
+
+    let announcement = config
+        .announcement_channel
+        .map(|channel| channel.mention().to_string())
+        .unwrap_or_else(|| t!(off).clone());
+
+    let range = mono(
+        config
+            .recommend_range
+            .unwrap_or(DEFAULT_RECOMMEND_RANGE)
+            .to_string(),
+    );
+
+    let disabled_commands = match config.disabled_commands.is_empty() {
+        true => t!(none).clone(),
+        false => config
+            .disabled_commands
+            .iter()
+            .map(|command| mono(format!("/{command}")))
+            .join(", "),
+    };
+
+    ctx.send(|r| {
+        r.embed(|e| {
+            e.color(Color::PURPLE)
+                .title(format!("{} {}", RikaMoji::Ok, t!(title)))
+                .field(t!(mode), mode, true)
+                .field(t!(locale), locale, true)
+                .field(t!(range), range, true)
+                .field(t!(announcement), announcement, false)
+                .field(t!(disabled_commands), disabled_commands, false)
+        })
+    })
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anyhow::anyhow;
+use itertools::Itertools;
+use lexicon::t_prefix;
+use poise::Command;
+use rika_model::{rika_cord, SharedRika};
+use roricon::RoriconTrait;
+
+use super::refresh;
+use crate::{
+    commands::CommandReturn,
+    utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
+};
+
+/// The full names of the commands and of all their subcommands, like `osu recommend osu`.
+fn qualified_names<U, E>(commands: &[Command<U, E>]) -> Vec<String> {
+    commands
+        .iter()
+        .flat_map(|command| {
+            [command.qualified_name.clone()]
+                .into_iter()
+                .chain(qualified_names(&command.subcommands))
+        })
+        .collect()
+}
+
+/// Commands that can be turned off, `/config` itself stays on so it can be undone.
+fn disableable_names(ctx: rika_cord::Context<'_>) -> Vec<String> {
+    qualified_names(&ctx.framework().options().commands)
+        .into_iter()
+        .filter(|name| name.split(' ').next() != Some("config"))
+        .collect()
+}
+
+async fn autocomplete_command(ctx: rika_cord::Context<'_>, partial: &str) -> Vec<String> {
+    disableable_names(ctx)
+        .into_iter()
+        .filter(|name| name.starts_with(partial.trim_start_matches('/')))
+        .take(25)
+        .collect()
+}
+
+/// Normalises what the user typed, like `/Osu  recommend`, into a full command name.
+fn normalise(command: &str) -> String {
+    command
+        .trim_start_matches('/')
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .join(" ")
+}
+
+/// Turns off a command and its subcommands in this server
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn disable(
+    ctx: rika_cord::Context<'_>,
+    #[description = "The full name of the command, like osu recommend"]
+    #[autocomplete = "autocomplete_command"]
+    command: String,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.config);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let guild_id = ctx.guild_id().ok_or(rika_cord::Error::Fallthrough)?;
+
+    let command = normalise(&command);
+
+    if !disableable_names(ctx).contains(&command) {
+        return Err(anyhow!(t!(unknown_command).r(mono(format!("/{command}")))))?;
+    }
+
+    sqlx::query!(
+        "INSERT IGNORE INTO guild_disabled_command (guild_id, command) VALUES (?, ?)",
+        &guild_id.0,
+        &command
+    )
+    .execute(db)
+    .await?;
+
+    refresh(&ctx, guild_id).await?;
+
+    ctx.say(cool_text(
+        RikaMoji::Ok,
+        &t!(disabled).r(mono(format!("/{command}"))),
+    ))
+    .await?;
+
+    Ok(())
+}
+
+/// Turns a command back on in this server
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn enable(
+    ctx: rika_cord::Context<'_>,
+    #[description = "The full name of the command, like osu recommend"]
+    #[autocomplete = "autocomplete_command"]
+    command: String,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.config);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let guild_id = ctx.guild_id().ok_or(rika_cord::Error::Fallthrough)?;
+
+    let command = normalise(&command);
+
+    let removed = sqlx::query!(
+        "DELETE FROM guild_disabled_command WHERE guild_id = ? AND command = ?",
+        &guild_id.0,
+        &command
+    )
+    .execute(db)
+    .await?
+    .rows_affected();
+
+    if removed == 0 {
+        return Err(anyhow!(t!(not_disabled).r(mono(format!("/{command}")))))?;
+    }
+
+    refresh(&ctx, guild_id).await?;
+
+    ctx.say(cool_text(
+        RikaMoji::Ok,
+        &t!(enabled).r(mono(format!("/{command}"))),
+    ))
+    .await?;
+
+    Ok(())
+}
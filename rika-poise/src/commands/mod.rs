@@ -2,6 +2,7 @@ use rika_model::rika_cord;
 
 pub mod owner;
 
+pub mod config;
 pub mod math;
 pub mod osu;
 pub mod rate;
@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use lexicon::t_prefix;
+use poise::serenity_prelude::{utils::parse_username, AttachmentType, Color, UserId};
+use rika_model::{osu::skills::weighted_skills, rika_cord, SharedRika};
+use roricon::RoriconTrait;
+
+use crate::{
+    commands::{
+        osu::{recommend::axis_label, OsuMode, RikaOsuContext},
+        CommandReturn,
+    },
+    utils::{
+        chart::{radar_chart, RadarSeries, SERIES_COLORS},
+        emojis::RikaMoji,
+        markdown::mono,
+    },
+};
+
+/// Finds the osu! id and username of either a mentioned linked user or an osu! username.
+pub async fn resolve_player(
+    ctx: rika_cord::Context<'_>,
+    input: &str,
+) -> Result<(u32, String), rika_cord::Error> {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.compare);
+
+    let SharedRika { rosu, .. } = ctx.data().shared.as_ref();
+
+    let osu_user = match parse_username(input) {
+        Some(discord_id) => {
+            let user = UserId(discord_id).to_user(ctx).await?;
+            let (.., osu_id) = ctx.linked_osu_user_of(&user).await?;
+
+            rosu.user(osu_id).await?
+        }
+        None => rosu
+            .user(input)
+            .await
+            .map_err(|_| anyhow!(t!(not_found).r(mono(input))))?,
+    };
+
+    Ok((osu_user.user_id, osu_user.username.to_string()))
+}
+
+/// Compares the weighted skills of two players.
+#[poise::command(slash_command)]
+pub async fn compare(
+    ctx: rika_cord::Context<'_>,
+    #[description = "A linked user mention or an osu! username"] user_a: String,
+    #[description = "A linked user mention or an osu! username, yourself by default"]
+    user_b: Option<String>,
+    mode: Option<OsuMode>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.compare);
+
+    let SharedRika { db, rosu, .. } = ctx.data().shared.as_ref();
+
+    let submit_mode = ctx.mode_or_default(mode).await?.submittable()?;
+
+    let player_a = resolve_player(ctx, &user_a).await?;
+    let player_b = match user_b {
+        Some(user_b) => resolve_player(ctx, &user_b).await?,
+        None => {
+            let (.., osu_id) = ctx.linked_osu_user().await?;
+            (osu_id, rosu.user(osu_id).await?.username.to_string())
+        }
+    };
+
+    let mut players = vec![];
+
+    for (osu_id, username) in [player_a, player_b] {
+        let (overall, skills) = weighted_skills(db, osu_id, submit_mode)
+            .await?
+            .ok_or_else(|| anyhow!(t!(not_submitted).r(mono(&username))))?;
+
+        let values = skills
+            .into_iter()
+            .chain([("overall", overall)])
+            .collect_vec();
+
+        players.push((username, values));
+    }
+
+    let [(name_a, values_a), (name_b, values_b)] = &players[..] else {
+        return Err(rika_cord::Error::Fallthrough);
+    };
+
+    let column = |values: &[(&str, f32)]| {
+        values
+            .iter()
+            .map(|(axis, pp)| {
+                format!(
+                    "{}: {}",
+                    axis_label(&i18n, axis),
+                    mono(format!("{pp:.2}pp"))
+                )
+            })
+            .join("\n")
+    };
+
+    let difference = values_a
+        .iter()
+        .zip(values_b)
+        .map(|((axis, a), (.., b))| {
+            format!(
+                "{}: {}",
+                axis_label(&i18n, axis),
+                mono(format!("{:+.2}", a - b))
+            )
+        })
+        .join("\n");
+
+    // A radar needs at least three corners to be any different from a line.
+    let radar = if values_a.len() >= 3 {
+        let series = [values_a, values_b]
+            .iter()
+            .zip(SERIES_COLORS)
+            .map(|(values, (color, ..))| RadarSeries {
+                color,
+                values: values.iter().map(|(.., pp)| *pp).collect(),
+            })
+            .collect_vec();
+
+        Some(radar_chart(values_a.len(), &series).map_err(|e| anyhow!(e))?)
+    } else {
+        None
+    };
+
+    let [(.., emoji_a), (.., emoji_b), ..] = SERIES_COLORS;
+    let has_radar = radar.is_some();
+
+    ctx.send(|r| {
+        if let Some(radar) = radar {
+            r.attachment(AttachmentType::Bytes {
+                data: Cow::Owned(radar),
+                filename: "compare.png".to_string(),
+            });
+        }
+
+        r.embed(|e| {
+            e.color(Color::PURPLE)
+                .title(format!(
+                    "{} {}",
+                    RikaMoji::Ok,
+                    t!(title).r((name_a.clone(), name_b.clone()))
+                ))
+                .field(format!("{emoji_a} {name_a}"), column(values_a), true)
+                .field(format!("{emoji_b} {name_b}"), column(values_b), true)
+                .field(t!(difference), difference, true);
+
+            if has_radar {
+                e.image("attachment://compare.png");
+            }
+
+            e
+        })
+    })
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use lexicon::t_prefix;
+use rika_model::{rika_cord, SharedRika};
+use roricon::RoriconTrait;
+
+use crate::{
+    commands::{osu::RikaOsuContext, CommandReturn},
+    models::osu_user::OsuUser,
+    utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
+};
+
+/// Deletes every link to your osu! account along with every score and snapshot stored for it,
+/// keeping it opted out for good.
+#[poise::command(slash_command)]
+pub async fn forget(ctx: rika_cord::Context<'_>) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.forget);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let (.., osu_id) = ctx.linked_osu_user().await?;
+
+    // The cached username, so forgetting still works when the osu! api does not.
+    let username = sqlx::query_scalar!("SELECT username FROM osu_user WHERE id = ?", &osu_id)
+        .fetch_optional(db)
+        .await?
+        .flatten()
+        .unwrap_or_else(|| format!("#{osu_id}"));
+
+    let mut tx = db.begin().await?;
+
+    // Shared links included, the account would otherwise still show up through them.
+    let discord_ids = sqlx::query_scalar!(
+        "SELECT discord_id FROM rika_user WHERE osu_id = ? FOR UPDATE",
+        &osu_id
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    for discord_id in discord_ids.iter().flatten() {
+        sqlx::query!(
+            "DELETE FROM leaderboard_snapshot WHERE discord_id = ?",
+            discord_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("DELETE FROM guild_member WHERE discord_id = ?", discord_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    sqlx::query!("DELETE FROM rika_user WHERE osu_id = ?", &osu_id)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query!(
+        "DELETE FROM link_event WHERE osu_id = ? OR discord_id = ?",
+        &osu_id,
+        &ctx.author().id.to_string()
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!("DELETE FROM link_verification WHERE osu_id = ?", &osu_id)
+        .execute(&mut *tx)
+        .await?;
+
+    // Scores, performances, snapshots and recommendations all cascade from the osu! user.
+    sqlx::query!("DELETE FROM osu_user WHERE id = ?", &osu_id)
+        .execute(&mut *tx)
+        .await?;
+
+    // Only the opt out is kept, otherwise the scraper would store everything again.
+    OsuUser::set_opted_out(&osu_id, true)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    ctx.say(cool_text(RikaMoji::Ok, &t!(forgotten).r(mono(username))))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use crate::commands::CommandReturn;
+use poise::command;
+
+mod replay;
+mod stable;
+
+use replay::replay;
+use rika_model::rika_cord;
+use stable::stable;
+
+#[command(slash_command, subcommands("replay", "stable"))]
+pub async fn import(_ctx: rika_cord::Context<'_>) -> CommandReturn {
+    Ok(())
+}
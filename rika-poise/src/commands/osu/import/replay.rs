@@ -0,0 +1,84 @@
+use anyhow::anyhow;
+use lexicon::t_prefix;
+use poise::serenity_prelude::Attachment;
+use rika_model::{
+    osu::{
+        stable::score::StableScore,
+        submit::{ScoreSource, ScoreSubmitter, SubmittableMode},
+    },
+    rika_cord, SharedRika,
+};
+use roricon::RoriconTrait;
+
+use crate::{
+    commands::{osu::RikaOsuContext, CommandReturn},
+    utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
+};
+
+/// Imports a score from an osu! replay file.
+#[poise::command(slash_command)]
+pub async fn replay(
+    ctx: rika_cord::Context<'_>,
+    #[description = "The .osr replay file"] replay: Attachment,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.import.replay);
+
+    let SharedRika {
+        rosu,
+        beatmap_cache,
+        score_submitter,
+        ..
+    } = ctx.data().shared.as_ref();
+
+    let (.., osu_id) = ctx.linked_osu_user().await?;
+
+    let replay_bytes = replay.download().await?;
+
+    let stable_score =
+        StableScore::from_replay(&replay_bytes).map_err(|_| anyhow!(t!(malformed).clone()))?;
+
+    SubmittableMode::try_from(stable_score.mode)
+        .map_err(|_| rika_cord::OsuError::UnsupportedMode)?;
+
+    let osu_user = rosu.user(osu_id).await?;
+
+    if !osu_user
+        .username
+        .to_string()
+        .eq_ignore_ascii_case(&stable_score.player)
+    {
+        return Err(anyhow!(t!(wrong_player).r(mono(&stable_score.player))))?;
+    }
+
+    let (map_id, mapper_id) = beatmap_cache
+        .get_beatmap_ids(rosu, &stable_score.beatmap_md5)
+        .await
+        .map_err(|_| anyhow!(t!(beatmap_not_found).clone()))?;
+
+    let (to_submit, ..) = ScoreSubmitter::begin_submission(score_submitter);
+
+    let imported = to_submit
+        .import_scores(
+            osu_id,
+            stable_score.mode,
+            ScoreSource::Replay,
+            vec![stable_score.submittable(map_id, mapper_id)],
+        )
+        .await?;
+
+    if imported.new == 0 {
+        return Err(anyhow!(t!(already_imported).clone()))?;
+    }
+
+    let beatmap_link = format!("https://osu.ppy.sh/b/{map_id}");
+
+    if imported.kept == 0 {
+        return Err(anyhow!(t!(outside_top).r(beatmap_link)))?;
+    }
+
+    ctx.say(cool_text(RikaMoji::Ok, &t!(imported).r(beatmap_link)))
+        .await?;
+
+    Ok(())
+}
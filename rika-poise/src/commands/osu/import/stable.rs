@@ -0,0 +1,64 @@
+use anyhow::anyhow;
+use itertools::Itertools;
+use lexicon::t_prefix;
+use poise::serenity_prelude::Attachment;
+use rika_model::{
+    osu::stable::{import::import_scores_db, scores_db::ScoresDatabase},
+    rika_cord, SharedRika,
+};
+use roricon::RoriconTrait;
+
+use crate::{
+    commands::{osu::RikaOsuContext, CommandReturn},
+    utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
+};
+
+/// Imports your scores from an osu!stable scores.db file.
+#[poise::command(slash_command)]
+pub async fn stable(
+    ctx: rika_cord::Context<'_>,
+    #[description = "Your osu!stable scores.db file"] scores: Attachment,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.import.stable);
+
+    let shared = ctx.data().shared.as_ref();
+    let SharedRika { rosu, .. } = shared;
+
+    let (.., osu_id) = ctx.linked_osu_user().await?;
+
+    // Only scores set by the linked player are imported, under any name they played with.
+    let osu_user = rosu.user(osu_id).await?;
+    let player = osu_user.username.to_string();
+
+    let players = [osu_user.username]
+        .into_iter()
+        .chain(osu_user.previous_usernames.into_iter().flatten())
+        .map(|name| name.to_string())
+        .collect_vec();
+
+    let scores_bytes = scores.download().await?;
+
+    let database =
+        ScoresDatabase::from_bytes(&scores_bytes).map_err(|_| anyhow!(t!(malformed).clone()))?;
+
+    if players
+        .iter()
+        .all(|player| database.scores_by(player).next().is_none())
+    {
+        return Err(anyhow!(t!(no_scores).r(mono(&player))))?;
+    }
+
+    let msg = ctx
+        .say(cool_text(RikaMoji::ChocolateBar, t!(importing)))
+        .await?;
+
+    let result = import_scores_db(shared, osu_id, &players, &database).await?;
+
+    let content = t!(imported).r((result.imported, result.missing_beatmaps));
+
+    msg.edit(ctx, |b| b.content(cool_text(RikaMoji::Ok, &content)))
+        .await?;
+
+    Ok(())
+}
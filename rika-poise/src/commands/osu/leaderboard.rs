@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use lexicon::t_prefix;
+use rika_model::{osu::skills::weighted_skills, rika_cord, SharedRika};
+use roricon::RoriconTrait;
+use sqlx::{MySql, QueryBuilder};
+
+use crate::{
+    commands::{
+        osu::{linked_guild_members, recommend::axis_label, OsuMode, RikaOsuContext, SkillAxis},
+        CommandReturn,
+    },
+    utils::{emojis::RikaMoji, markdown::mono, paginate::paginate_embed},
+};
+
+/// How many members are listed on each page.
+const LEADERBOARD_PAGE_SIZE: usize = 10;
+
+/// Ranks the linked members of the server by their weighted skills.
+#[poise::command(slash_command, guild_only)]
+pub async fn leaderboard(
+    ctx: rika_cord::Context<'_>,
+    mode: Option<OsuMode>,
+    #[description = "The skill to rank by, overall pp by default"] axis: Option<SkillAxis>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.leaderboard);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let guild_id = ctx.guild_id().ok_or(rika_cord::Error::Fallthrough)?;
+
+    let mode = ctx.mode_or_default(mode).await?;
+    let submit_mode = mode.submittable()?;
+    let axis = axis.unwrap_or_default();
+    let axis_name = axis_label(&i18n, axis.column());
+
+    if !axis.is_in(submit_mode) {
+        return Err(anyhow!(t!(invalid_axis).r(mono(axis_name))))?;
+    }
+
+    ctx.defer().await?;
+
+    let mut ranking = vec![];
+
+    for (member, osu_id) in linked_guild_members(ctx, db, guild_id).await? {
+        let Some(skills) = weighted_skills(db, osu_id, submit_mode).await? else {
+            continue;
+        };
+
+        if let Some(value) = axis.value_of(&skills) {
+            ranking.push((member, value));
+        }
+    }
+
+    if ranking.is_empty() {
+        return Err(anyhow!(t!(empty).clone()))?;
+    }
+
+    ranking.sort_by(|(.., a), (.., b)| b.total_cmp(a));
+
+    #[derive(sqlx::FromRow)]
+    struct PreviousPosition {
+        discord_id: String,
+        position: u32,
+    }
+
+    let previous_positions: HashMap<_, _> = sqlx::query_as::<_, PreviousPosition>(
+        "
+        SELECT discord_id, position FROM leaderboard_snapshot
+        WHERE guild_id = ? AND mode = ? AND axis = ?
+        ",
+    )
+    .bind(guild_id.0)
+    .bind(mode as i16)
+    .bind(axis.column())
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|p| (p.discord_id, p.position))
+    .collect();
+
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM leaderboard_snapshot
+        WHERE guild_id = ? AND mode = ? AND axis = ?
+        ",
+        &guild_id.0,
+        &(mode as i16),
+        &axis.column()
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let mut snapshot_query = QueryBuilder::<MySql>::new(
+        "INSERT INTO leaderboard_snapshot (guild_id, mode, axis, discord_id, position)",
+    );
+
+    snapshot_query.push_values(ranking.iter().enumerate(), |mut b, (i, (member, ..))| {
+        b.push_bind(guild_id.0)
+            .push_bind(mode as i16)
+            .push_bind(axis.column())
+            .push_bind(member.user.id.to_string())
+            .push_bind(i as u32 + 1);
+    });
+
+    snapshot_query.build().execute(&mut *tx).await?;
+
+    tx.commit().await?;
+
+    let lines = ranking
+        .iter()
+        .enumerate()
+        .map(|(i, (member, value))| {
+            let position = i as u32 + 1;
+
+            let change = match previous_positions.get(&member.user.id.to_string()) {
+                None => t!(new_entry).clone(),
+                Some(previous) if *previous > position => format!("▲{}", previous - position),
+                Some(previous) if *previous < position => format!("▼{}", position - previous),
+                Some(..) => "•".to_string(),
+            };
+
+            format!(
+                "{position}. {} {} {change}",
+                member.display_name(),
+                mono(format!("{value:.2}pp"))
+            )
+        })
+        .collect_vec();
+
+    let pages = lines
+        .chunks(LEADERBOARD_PAGE_SIZE)
+        .map(|chunk| chunk.join("\n"))
+        .collect_vec();
+
+    paginate_embed(
+        ctx,
+        &format!("{} {}", RikaMoji::Ok, t!(title).r(axis_name)),
+        &pages,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -1,6 +1,13 @@
 use anyhow::anyhow;
 use lexicon::t_prefix;
-use rika_model::{rika_cord, SharedRika};
+use rand::{distributions::Alphanumeric, Rng};
+use rika_model::{
+    osu::{
+        link::is_claimed_by_other,
+        verification::{create_link_code, LINK_CODE_MINUTES},
+    },
+    rika_cord, SharedRika,
+};
 use roricon::RoriconTrait;
 
 use crate::{
@@ -9,12 +16,21 @@ use crate::{
     utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
 };
 
+/// How many characters the code sent on Bancho has.
+const LINK_CODE_LENGTH: usize = 6;
+
 #[poise::command(slash_command)]
 pub async fn link(ctx: rika_cord::Context<'_>, name: String) -> CommandReturn {
     let i18n = ctx.i18n();
     t_prefix!($, i18n.osu.link);
 
-    let SharedRika { db, rosu, .. } = ctx.data().shared.as_ref();
+    let SharedRika {
+        db,
+        rosu,
+        link_policy,
+        ..
+    } = ctx.data().shared.as_ref();
+    let config = &ctx.data().config;
 
     let osu_user = rosu
         .user(&name)
@@ -22,31 +38,50 @@ pub async fn link(ctx: rika_cord::Context<'_>, name: String) -> CommandReturn {
         .map_err(|_| anyhow!(t!(failed).r(name.clone())))?;
 
     let osu_user_id = osu_user.user_id;
+    let discord_id = ctx.author().id.to_string();
 
-    let mut tx = db.begin().await?;
-
-    sqlx::query!(
-        "
-        INSERT INTO rika_user (discord_id, osu_id)
-        VALUES (?, ?)
-        ON DUPLICATE KEY UPDATE
-            osu_id = VALUES(osu_id)
-        ",
-        &ctx.author().id.to_string(),
-        &osu_user_id
+    if is_claimed_by_other(db, *link_policy, osu_user_id, &discord_id).await? {
+        return Err(anyhow!(
+            t!(already_claimed).r(mono(osu_user.username.to_string()))
+        ))?;
+    }
+
+    OsuUser::upsert_profile(
+        &osu_user_id,
+        osu_user.username.as_str(),
+        osu_user.country_code.as_str(),
     )
-    .execute(&mut *tx)
+    .execute(db)
     .await?;
 
-    OsuUser::try_create(&osu_user_id).execute(&mut *tx).await?;
+    let code = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(LINK_CODE_LENGTH)
+        .map(char::from)
+        .collect::<String>()
+        .to_uppercase();
 
-    tx.commit().await?;
+    // The link is only stored once the code is sent from the osu! account itself.
+    create_link_code(
+        db,
+        &code,
+        &discord_id,
+        osu_user_id,
+        osu_user.username.as_str(),
+    )
+    .await?;
 
-    ctx.say(cool_text(
+    let content = cool_text(
         RikaMoji::Ok,
-        &t!(linked).r(mono(osu_user.username.to_string())),
-    ))
-    .await?;
+        &t!(code).r((
+            mono(osu_user.username.to_string()),
+            mono(format!("{}verify {code}", config.bancho_prefix)),
+            mono(&config.bancho_username),
+            LINK_CODE_MINUTES,
+        )),
+    );
+
+    ctx.send(|r| r.content(content).ephemeral(true)).await?;
 
     Ok(())
 }
@@ -1,18 +1,71 @@
+pub mod compare;
+pub mod forget;
+pub mod import;
+pub mod leaderboard;
 pub mod link;
+pub mod playlist;
+pub mod privacy;
+pub mod profile;
+pub mod progress;
 pub mod recommend;
+pub mod rivals;
 pub mod submit;
+pub mod top;
+pub mod top_scores;
+pub mod unlink;
 
+use std::sync::Arc;
+
+use compare::compare;
+use forget::forget;
+use import::import;
+use leaderboard::leaderboard;
 use link::link;
-use poise::{async_trait, command, ChoiceParameter};
-use recommend::recommend;
-use rika_model::{rika_cord, SharedRika};
+use playlist::playlist;
+use poise::{
+    async_trait, command,
+    serenity_prelude::{CacheHttp, GuildId, Member, SerenityError, StatusCode, User, UserId},
+    ChoiceParameter,
+};
+use privacy::privacy;
+use profile::profile;
+use progress::progress;
+use recommend::{recommend, DEFAULT_RECOMMEND_RANGE};
+use rika_model::{
+    osu::{skills::SkillVector, submit::SubmittableMode},
+    rika_cord::{self, guild_config::GuildConfig},
+    SharedRika,
+};
+use rivals::rivals;
 use rosu_v2::prelude::GameMode;
-use sqlx::Result;
+use sqlx::{MySqlPool, Result};
 use submit::submit;
+use top::top;
+use top_scores::top_scores;
+use unlink::unlink;
 
 use crate::commands::CommandReturn;
 
-#[command(slash_command, subcommands("link", "submit", "recommend"))]
+#[command(
+    slash_command,
+    subcommands(
+        "link",
+        "submit",
+        "recommend",
+        "import",
+        "playlist",
+        "profile",
+        "progress",
+        "compare",
+        "leaderboard",
+        "rivals",
+        "top",
+        "top_scores",
+        "privacy",
+        "forget",
+        "unlink"
+    )
+)]
 pub async fn osu(_ctx: rika_cord::Context<'_>) -> CommandReturn {
     Ok(())
 }
@@ -34,32 +87,237 @@ pub enum OsuMode {
     Mania = 3,
 }
 
+#[derive(ChoiceParameter, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SkillAxis {
+    #[default]
+    #[name = "overall"]
+    Overall,
+
+    #[name = "aim"]
+    Aim,
+
+    #[name = "speed"]
+    Speed,
+
+    #[name = "accuracy"]
+    Accuracy,
+
+    #[name = "flashlight"]
+    Flashlight,
+
+    #[name = "difficulty"]
+    Difficulty,
+}
+
+impl SkillAxis {
+    /// The column of the axis in the performance tables.
+    pub fn column(&self) -> &'static str {
+        match self {
+            Self::Overall => "overall",
+            Self::Aim => "aim",
+            Self::Speed => "speed",
+            Self::Accuracy => "accuracy",
+            Self::Flashlight => "flashlight",
+            Self::Difficulty => "difficulty",
+        }
+    }
+
+    /// Whether the performance table of the mode has the axis.
+    pub fn is_in(&self, mode: SubmittableMode) -> bool {
+        *self == Self::Overall || mode.axes().contains(&self.column())
+    }
+
+    /// The weighted value of the axis out of a skill vector.
+    pub fn value_of(&self, (overall, skills): &SkillVector) -> Option<f32> {
+        match self {
+            Self::Overall => Some(*overall),
+            axis => skills
+                .iter()
+                .find(|(name, ..)| *name == axis.column())
+                .map(|(.., value)| *value),
+        }
+    }
+}
+
+impl OsuMode {
+    pub fn submittable(self) -> Result<SubmittableMode, rika_cord::OsuError> {
+        SubmittableMode::try_from(GameMode::from(self))
+            .map_err(|_| rika_cord::OsuError::UnsupportedMode)
+    }
+}
+
 impl From<OsuMode> for GameMode {
     fn from(val: OsuMode) -> Self {
         GameMode::from(val as u8)
     }
 }
 
+impl From<GameMode> for OsuMode {
+    fn from(val: GameMode) -> Self {
+        match val {
+            GameMode::Osu => Self::Osu,
+            GameMode::Taiko => Self::Taiko,
+            GameMode::Catch => Self::Catch,
+            GameMode::Mania => Self::Mania,
+        }
+    }
+}
+
+/// The config of the guild the command runs in, or the defaults outside of guilds.
+async fn current_guild_config(
+    ctx: &rika_cord::Context<'_>,
+) -> Result<Arc<GuildConfig>, rika_cord::Error> {
+    match ctx.guild_id() {
+        Some(guild_id) => Ok(ctx.data().guild_config(guild_id).await?),
+        None => Ok(Arc::default()),
+    }
+}
+
+/// The osu! id linked to the user, and whether the link was verified.
+async fn linked_osu_id(
+    ctx: &rika_cord::Context<'_>,
+    user: &User,
+) -> Result<(u32, bool), rika_cord::OsuError> {
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let user = sqlx::query!(
+        "SELECT * FROM rika_user WHERE discord_id=?",
+        &user.id.to_string()
+    )
+    .fetch_one(db)
+    .await
+    .map_err(|_| rika_cord::OsuError::NotLinked)?;
+
+    let osu_id = user.osu_id.ok_or(rika_cord::OsuError::NotLinked)?;
+
+    Ok((osu_id, user.verified_at.is_some()))
+}
+
 #[async_trait]
 pub trait RikaOsuContext {
     async fn linked_osu_user(&self) -> Result<((), u32), rika_cord::OsuError>;
+
+    async fn linked_osu_user_of(&self, user: &User) -> Result<((), u32), rika_cord::OsuError>;
+
+    /// The osu! account the author linked, even if the link was never verified.
+    async fn claimed_osu_user(&self) -> Result<((), u32), rika_cord::OsuError>;
+
+    /// The given mode, or the default mode of the guild.
+    async fn mode_or_default(&self, mode: Option<OsuMode>) -> Result<OsuMode, rika_cord::Error>;
+
+    /// The given recommendation range, or the default range of the guild.
+    async fn recommend_range_or_default(&self, range: Option<f32>)
+        -> Result<f32, rika_cord::Error>;
 }
 
 #[async_trait]
 impl RikaOsuContext for rika_cord::Context<'_> {
     async fn linked_osu_user(&self) -> Result<((), u32), rika_cord::OsuError> {
-        let SharedRika { db, .. } = self.data().shared.as_ref();
+        self.linked_osu_user_of(self.author()).await
+    }
 
-        let user = sqlx::query!(
-            "SELECT * FROM rika_user WHERE discord_id=?",
-            &self.author().id.to_string()
-        )
-        .fetch_one(db)
-        .await
-        .map_err(|_| rika_cord::OsuError::NotLinked)?;
+    async fn linked_osu_user_of(&self, user: &User) -> Result<((), u32), rika_cord::OsuError> {
+        let (osu_id, verified) = linked_osu_id(self, user).await?;
 
-        let osu_id = user.osu_id.ok_or(rika_cord::OsuError::NotLinked)?;
+        // Links made before verification existed only claim the account.
+        if !verified {
+            return Err(rika_cord::OsuError::NotVerified);
+        }
 
         Ok(((), osu_id))
     }
+
+    async fn claimed_osu_user(&self) -> Result<((), u32), rika_cord::OsuError> {
+        let (osu_id, ..) = linked_osu_id(self, self.author()).await?;
+
+        Ok(((), osu_id))
+    }
+
+    async fn mode_or_default(&self, mode: Option<OsuMode>) -> Result<OsuMode, rika_cord::Error> {
+        if let Some(mode) = mode {
+            return Ok(mode);
+        }
+
+        let config = current_guild_config(self).await?;
+
+        Ok(config.default_mode.map(OsuMode::from).unwrap_or_default())
+    }
+
+    async fn recommend_range_or_default(
+        &self,
+        range: Option<f32>,
+    ) -> Result<f32, rika_cord::Error> {
+        if let Some(range) = range {
+            return Ok(range);
+        }
+
+        let config = current_guild_config(self).await?;
+
+        Ok(config.recommend_range.unwrap_or(DEFAULT_RECOMMEND_RANGE))
+    }
+}
+
+/// Remembers the user as a member of the guild, so [`linked_guild_members`] can list them.
+pub async fn remember_guild_member(
+    db: &MySqlPool,
+    guild_id: GuildId,
+    user_id: UserId,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "INSERT IGNORE INTO guild_member (guild_id, discord_id) VALUES (?, ?)",
+        &guild_id.0,
+        &user_id.to_string()
+    )
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Every linked user that is a member of the guild, alongside their osu! id.
+///
+/// Listing every member of a guild needs a privileged intent, so only the linked users seen using
+/// a command in the guild are looked up, and forgotten once they are no longer members.
+pub async fn linked_guild_members(
+    cache_http: impl CacheHttp,
+    db: &MySqlPool,
+    guild_id: GuildId,
+) -> Result<Vec<(Member, u32)>, rika_cord::Error> {
+    let linked_users = sqlx::query!(
+        "
+        SELECT r.discord_id, r.osu_id FROM rika_user r
+        JOIN guild_member m ON m.discord_id = r.discord_id
+        WHERE m.guild_id = ? AND r.osu_id IS NOT NULL AND r.verified_at IS NOT NULL
+        ",
+        &guild_id.0
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut members = vec![];
+
+    for linked_user in linked_users {
+        let (Some(discord_id), Some(osu_id)) = (
+            linked_user.discord_id.and_then(|id| id.parse().ok()),
+            linked_user.osu_id,
+        ) else {
+            continue;
+        };
+
+        match guild_id.member(&cache_http, UserId(discord_id)).await {
+            Ok(member) => members.push((member, osu_id)),
+            Err(SerenityError::Http(e)) if e.status_code() == Some(StatusCode::NOT_FOUND) => {
+                sqlx::query!(
+                    "DELETE FROM guild_member WHERE guild_id = ? AND discord_id = ?",
+                    &guild_id.0,
+                    &discord_id.to_string()
+                )
+                .execute(db)
+                .await?;
+            }
+            Err(..) => {}
+        }
+    }
+
+    Ok(members)
 }
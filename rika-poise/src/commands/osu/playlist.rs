@@ -0,0 +1,174 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use lexicon::t_prefix;
+use poise::serenity_prelude::AttachmentType;
+use rika_model::{
+    osu::{
+        stable::collection::{Collection, CollectionDatabase},
+        submit::SubmittableMode,
+    },
+    rika_cord, SharedRika,
+};
+use roricon::RoriconTrait;
+use rosu_v2::prelude::{GameMode, GameMods};
+
+use crate::{
+    commands::{
+        osu::{
+            recommend::{
+                feedback::fetch_tuning, mid_interval, query_recommendation,
+                strategy::RecommendStrategy, weighted_skills, ModsFilter, RecommendExclusion,
+                RecommendationQuery,
+            },
+            OsuMode, RikaOsuContext,
+        },
+        CommandReturn,
+    },
+    utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
+};
+
+/// How many times a step of the playlist is retried when it lands on an already picked map.
+const STEP_ATTEMPTS: usize = 3;
+
+/// Builds a playlist of recommendations as an osu! collection.
+#[poise::command(slash_command)]
+pub async fn playlist(
+    ctx: rika_cord::Context<'_>,
+    mode: Option<OsuMode>,
+    #[description = "How many maps the playlist should have"]
+    #[min = 1]
+    #[max = 25]
+    count: Option<u8>,
+    #[description = "How much harder the last map is than the first one, 0.3 by default"]
+    #[min = 0.0]
+    #[max = 1.0]
+    ramp: Option<f32>,
+    #[description = "How far from your skills the maps may be, the server's range by default"]
+    #[min = 0.05]
+    #[max = 2.0]
+    range: Option<f32>,
+    exclude: Option<RecommendExclusion>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.playlist);
+
+    let SharedRika {
+        db, beatmap_cache, ..
+    } = ctx.data().shared.as_ref();
+
+    let mode = ctx.mode_or_default(mode).await?;
+    let count = count.unwrap_or(10) as usize;
+    let ramp = ramp.unwrap_or(0.3);
+    let range = ctx.recommend_range_or_default(range).await?;
+
+    let submit_mode = SubmittableMode::try_from(GameMode::from(mode))
+        .map_err(|_| rika_cord::OsuError::UnsupportedMode)?;
+
+    let (.., osu_id) = ctx.linked_osu_user().await?;
+
+    let (overall, skills) = weighted_skills(db, osu_id, mode).await?;
+    let tuning = fetch_tuning(db, osu_id, mode).await?;
+
+    let mut picked_maps = HashSet::new();
+    let mut playlist = vec![];
+
+    for step in 0..count {
+        let progress = match count {
+            1 => 0.5,
+            _ => step as f32 / (count - 1) as f32,
+        };
+
+        let factor = 1.0 + ramp * (progress - 0.5);
+
+        for _ in 0..STEP_ATTEMPTS {
+            let values = skills
+                .iter()
+                .map(|(name, value)| {
+                    let (centre, spread) = tuning.get(*name).copied().unwrap_or((1.0, 1.0));
+
+                    (*name, mid_interval(value * factor * centre, range * spread))
+                })
+                .collect_vec();
+
+            let recommendation_query = RecommendationQuery {
+                osu_id,
+                mode: submit_mode,
+                overall: overall * factor,
+                values,
+                mods: ModsFilter::default(),
+                exclusion: exclude.unwrap_or_default(),
+                cooldown_hours: ctx.data().config.recommendation_cooldown_hours,
+                strategy: RecommendStrategy::Comfort,
+                seed: ctx.id().wrapping_add(step as u64),
+            };
+
+            let Ok(recommendation) = query_recommendation(db, recommendation_query).await else {
+                break;
+            };
+
+            if picked_maps.insert(recommendation.score.map_id) {
+                playlist.push(recommendation);
+                break;
+            }
+        }
+    }
+
+    // Maps without a checksum can't go in the collection, so they're left out of the links too.
+    let mut entries = vec![];
+
+    for recommendation in playlist {
+        if let Ok(checksum) = beatmap_cache
+            .get_beatmap_checksum(recommendation.score.map_id)
+            .await
+        {
+            entries.push((recommendation, checksum));
+        }
+    }
+
+    if entries.is_empty() {
+        return Err(anyhow!(t!(not_found).clone()))?;
+    }
+
+    let collection_db = CollectionDatabase {
+        collections: vec![Collection {
+            name: t!(collection_name).r(ctx.author().name.clone()),
+            beatmap_md5s: entries
+                .iter()
+                .map(|(_, checksum)| checksum.clone())
+                .collect(),
+        }],
+    };
+
+    let links = entries
+        .iter()
+        .enumerate()
+        .map(|(i, (recommendation, _))| {
+            let displayable_mods =
+                GameMods::from_bits(recommendation.score.mods).unwrap_or_default();
+
+            format!(
+                "{}. https://osu.ppy.sh/b/{} {}",
+                i + 1,
+                recommendation.score.map_id,
+                mono(displayable_mods.to_string())
+            )
+        })
+        .join("\n");
+
+    let content = format!(
+        "{}\n{links}",
+        cool_text(RikaMoji::Ok, &t!(built).r(entries.len()))
+    );
+
+    ctx.send(|r| {
+        r.content(content).attachment(AttachmentType::Bytes {
+            data: Cow::Owned(collection_db.to_bytes()),
+            filename: "collection.db".to_string(),
+        })
+    })
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use lexicon::t_prefix;
+use poise::command;
+use rika_model::{rika_cord, SharedRika};
+use roricon::RoriconTrait;
+
+use crate::{
+    commands::{osu::RikaOsuContext, CommandReturn},
+    models::osu_user::OsuUser,
+    utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
+};
+
+#[command(slash_command, subcommands("opt_out", "opt_in"))]
+pub async fn privacy(_ctx: rika_cord::Context<'_>) -> CommandReturn {
+    Ok(())
+}
+
+/// Stops the scraper and submissions from storing scores of your linked account.
+#[command(slash_command, rename = "opt-out")]
+pub async fn opt_out(ctx: rika_cord::Context<'_>) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.privacy);
+
+    let username = set_opted_out(ctx, true).await?;
+
+    ctx.say(cool_text(RikaMoji::Ok, &t!(opted_out).r(mono(username))))
+        .await?;
+
+    Ok(())
+}
+
+/// Lets the scraper and submissions store scores of your linked account again.
+#[command(slash_command, rename = "opt-in")]
+pub async fn opt_in(ctx: rika_cord::Context<'_>) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.privacy);
+
+    let username = set_opted_out(ctx, false).await?;
+
+    ctx.say(cool_text(RikaMoji::Ok, &t!(opted_in).r(mono(username))))
+        .await?;
+
+    Ok(())
+}
+
+/// Returns the username of the linked account.
+async fn set_opted_out(
+    ctx: rika_cord::Context<'_>,
+    opted_out: bool,
+) -> Result<String, rika_cord::Error> {
+    let SharedRika { db, rosu, .. } = ctx.data().shared.as_ref();
+
+    let (.., osu_id) = ctx.linked_osu_user().await?;
+
+    OsuUser::set_opted_out(&osu_id, opted_out)
+        .execute(db)
+        .await?;
+
+    Ok(rosu.user(osu_id).await?.username.to_string())
+}
@@ -0,0 +1,125 @@
+use itertools::Itertools;
+use lexicon::t_prefix;
+use poise::serenity_prelude::{self, Color};
+use rika_model::{osu::submit::SubmittableMode, rika_cord, SharedRika};
+use roricon::RoriconTrait;
+use rosu_v2::prelude::GameMode;
+use sqlx::{MySql, Pool, QueryBuilder, Row};
+
+use crate::{
+    commands::{
+        osu::{
+            recommend::{axis_label, weighted_skills},
+            OsuMode, RikaOsuContext,
+        },
+        CommandReturn,
+    },
+    utils::{emojis::RikaMoji, markdown::mono},
+};
+
+/// Which percentage of the ranked users are below each of the given weighted values, read from
+/// the cached skill ranking instead of weighing the scores of every user.
+pub async fn skill_percentiles(
+    db: &Pool<MySql>,
+    submit_mode: SubmittableMode,
+    values: &[(&str, f32)],
+) -> Result<Vec<f64>, sqlx::Error> {
+    let mode_bits = GameMode::from(submit_mode) as i16;
+
+    let mut query = QueryBuilder::<MySql>::new("SELECT ");
+    let mut percentiles = query.separated(", ");
+
+    for (axis, value) in values {
+        percentiles.push("(SELECT CAST(AVG(value < ");
+        percentiles.push_bind_unseparated(value);
+        percentiles.push_unseparated(") * 100 AS DOUBLE) FROM skill_ranking WHERE mode = ");
+        percentiles.push_bind_unseparated(mode_bits);
+        percentiles.push_unseparated(format!(" AND axis = '{axis}') AS {axis}"));
+    }
+
+    let row = query.build().fetch_one(db).await?;
+
+    values
+        .iter()
+        .map(|(axis, ..)| Ok(row.try_get::<Option<f64>, _>(*axis)?.unwrap_or_default()))
+        .collect()
+}
+
+/// Shows the weighted skills of a linked user.
+#[poise::command(slash_command)]
+pub async fn profile(
+    ctx: rika_cord::Context<'_>,
+    #[description = "Selected user"] user: Option<serenity_prelude::User>,
+    mode: Option<OsuMode>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.profile);
+
+    let SharedRika { db, rosu, .. } = ctx.data().shared.as_ref();
+
+    let mode = ctx.mode_or_default(mode).await?;
+    let (.., osu_id) = match &user {
+        Some(user) => ctx.linked_osu_user_of(user).await?,
+        None => ctx.linked_osu_user().await?,
+    };
+
+    let (overall, skills) = weighted_skills(db, osu_id, mode).await?;
+
+    let values = skills
+        .iter()
+        .copied()
+        .chain([("overall", overall)])
+        .collect::<Vec<_>>();
+
+    let percentiles = skill_percentiles(db, mode.submittable()?, &values).await?;
+
+    let stored = sqlx::query!(
+        "
+        SELECT COUNT(*) AS amount, MAX(created_at) AS last_submitted FROM osu_score
+        WHERE osu_user_id = ? AND mode = ?
+        ",
+        &osu_id,
+        &(mode as i16)
+    )
+    .fetch_one(db)
+    .await?;
+
+    let osu_user = rosu.user(osu_id).mode(GameMode::from(mode)).await?;
+
+    let skill_lines = values
+        .iter()
+        .zip(percentiles)
+        .map(|((axis, pp), percentile)| {
+            format!(
+                "{}: {} ({})",
+                axis_label(&i18n, axis),
+                mono(format!("{pp:.2}pp")),
+                t!(percentile).r(mono(format!("{percentile:.1}%")))
+            )
+        })
+        .join("\n");
+
+    let last_submitted = stored
+        .last_submitted
+        .map(|at| format!("<t:{}:R>", at.unix_timestamp()))
+        .unwrap_or_else(|| "-".to_string());
+
+    ctx.send(|r| {
+        r.embed(|e| {
+            e.color(Color::PURPLE)
+                .title(format!(
+                    "{} {}",
+                    RikaMoji::Ok,
+                    t!(title).r(osu_user.username.to_string())
+                ))
+                .url(format!("https://osu.ppy.sh/users/{osu_id}"))
+                .field(t!(skills), skill_lines, false)
+                .field(t!(overall), mono(format!("{overall:.2}pp")), true)
+                .field(t!(scores), mono(stored.amount.to_string()), true)
+                .field(t!(last_submitted), last_submitted, true)
+        })
+    })
+    .await?;
+
+    Ok(())
+}
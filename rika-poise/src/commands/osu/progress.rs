@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use lexicon::t_prefix;
+use poise::serenity_prelude::{self, AttachmentType, Color};
+use rika_model::{rika_cord, SharedRika};
+use roricon::RoriconTrait;
+use sqlx::types::time::OffsetDateTime;
+
+use crate::{
+    commands::{
+        osu::{
+            recommend::{axis_label, mode_axes},
+            OsuMode, RikaOsuContext,
+        },
+        CommandReturn,
+    },
+    utils::{
+        chart::{line_chart, ChartSeries, SERIES_COLORS},
+        emojis::RikaMoji,
+        markdown::mono,
+    },
+};
+
+#[derive(sqlx::FromRow)]
+struct SkillSnapshot {
+    axis: String,
+    value: f32,
+    created_at: OffsetDateTime,
+}
+
+/// Charts how the weighted skills of a linked user changed on every submission.
+#[poise::command(slash_command)]
+pub async fn progress(
+    ctx: rika_cord::Context<'_>,
+    #[description = "Selected user"] user: Option<serenity_prelude::User>,
+    mode: Option<OsuMode>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.progress);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let mode = ctx.mode_or_default(mode).await?;
+    let (.., osu_id) = match &user {
+        Some(user) => ctx.linked_osu_user_of(user).await?,
+        None => ctx.linked_osu_user().await?,
+    };
+
+    let snapshots: Vec<SkillSnapshot> = sqlx::query_as(
+        "
+        SELECT axis, value, created_at FROM skill_snapshot
+        WHERE osu_user_id = ? AND mode = ?
+        ORDER BY created_at ASC
+        ",
+    )
+    .bind(osu_id)
+    .bind(mode as i16)
+    .fetch_all(db)
+    .await?;
+
+    let (Some(first), Some(last)) = (snapshots.first(), snapshots.last()) else {
+        return Err(anyhow!(t!(not_enough).clone()))?;
+    };
+
+    if first.created_at == last.created_at {
+        return Err(anyhow!(t!(not_enough).clone()))?;
+    }
+
+    let period = t!(period).r((
+        format!("<t:{}:D>", first.created_at.unix_timestamp()),
+        format!("<t:{}:D>", last.created_at.unix_timestamp()),
+    ));
+
+    let mut series = vec![];
+    let mut legend = vec![];
+
+    for (axis, (color, emoji)) in mode_axes(mode)
+        .iter()
+        .copied()
+        .chain(["overall"])
+        .zip(SERIES_COLORS)
+    {
+        let points = snapshots
+            .iter()
+            .filter(|snapshot| snapshot.axis == axis)
+            .map(|snapshot| (snapshot.created_at.unix_timestamp() as f64, snapshot.value))
+            .collect_vec();
+
+        let (Some((.., from)), Some((.., to))) = (points.first(), points.last()) else {
+            continue;
+        };
+
+        legend.push(format!(
+            "{emoji} {}: {} ({:+.2})",
+            axis_label(&i18n, axis),
+            mono(format!("{to:.2}pp")),
+            to - from
+        ));
+
+        series.push(ChartSeries { color, points });
+    }
+
+    let chart = line_chart(&series).map_err(|e| anyhow!(e))?;
+
+    ctx.send(|r| {
+        r.attachment(AttachmentType::Bytes {
+            data: Cow::Owned(chart),
+            filename: "progress.png".to_string(),
+        })
+        .embed(|e| {
+            e.color(Color::PURPLE)
+                .title(format!("{} {}", RikaMoji::Ok, t!(title)))
+                .description(format!("{period}\n\n{}", legend.join("\n")))
+                .image("attachment://progress.png")
+        })
+    })
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,328 @@
+use std::{collections::HashMap, str::FromStr};
+
+use lexicon::{t_prefix, LocaleAccess, Localizer};
+use poise::serenity_prelude::{ButtonStyle, CreateActionRow};
+use rika_model::i18n::{rika_localizer::RikaLocalizer, RikaLocale};
+use sqlx::{MySql, Pool, Row};
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
+
+use crate::{
+    commands::osu::{recommend::mode_axes, OsuMode},
+    models::osu_score::OsuScore,
+};
+
+/// How much a single "too easy" or "too hard" moves the centre of the user's skill window.
+const CENTRE_STEP: f32 = 0.05;
+
+/// How much a single "played it" or "not my style" narrows or widens the user's skill window.
+const SPREAD_STEP: f32 = 0.05;
+
+/// How far feedback can move the centre of the user's skill window, as a multiplier.
+const CENTRE_BOUNDS: (f32, f32) = (0.5, 1.5);
+
+/// How far feedback can narrow or widen the user's skill window, as a multiplier.
+const SPREAD_BOUNDS: (f32, f32) = (0.25, 2.0);
+
+#[derive(Display, EnumString, EnumIter, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "snake_case")]
+pub enum RecommendationFeedback {
+    PlayedIt,
+    TooEasy,
+    TooHard,
+    NotMyStyle,
+}
+
+impl RecommendationFeedback {
+    fn label(&self, i18n: &LocaleAccess<Localizer<RikaLocale, RikaLocalizer>>) -> String {
+        t_prefix!($, i18n.osu.recommend.feedback);
+
+        match self {
+            Self::PlayedIt => t!(played_it),
+            Self::TooEasy => t!(too_easy),
+            Self::TooHard => t!(too_hard),
+            Self::NotMyStyle => t!(not_my_style),
+        }
+        .clone()
+    }
+
+    pub fn from_custom_id(prefix: &str, custom_id: &str) -> Option<Self> {
+        custom_id
+            .strip_prefix(prefix)
+            .and_then(|kind| Self::from_str(kind).ok())
+    }
+
+    fn style(&self) -> ButtonStyle {
+        match self {
+            Self::PlayedIt => ButtonStyle::Success,
+            Self::TooEasy | Self::TooHard => ButtonStyle::Primary,
+            Self::NotMyStyle => ButtonStyle::Danger,
+        }
+    }
+
+    /// How the centre and the spread of the skill window move on an axis, given how much of the
+    /// recommended score's performance came from that axis.
+    fn tuning(&self, share: f32) -> (f32, f32) {
+        match self {
+            Self::PlayedIt => (0.0, -SPREAD_STEP * share),
+            Self::TooEasy => (CENTRE_STEP * share, 0.0),
+            Self::TooHard => (-CENTRE_STEP * share, 0.0),
+            Self::NotMyStyle => (0.0, SPREAD_STEP * share),
+        }
+    }
+
+    /// The tuning of this feedback with the tuning of the feedback it replaces undone.
+    fn replacing(&self, previous: Option<Self>, share: f32) -> (f32, f32) {
+        let (centre, spread) = self.tuning(share);
+        let (previous_centre, previous_spread) =
+            previous.map_or((0.0, 0.0), |previous| previous.tuning(share));
+
+        (centre - previous_centre, spread - previous_spread)
+    }
+}
+
+/// Moves a stored tuning by the given steps, keeping it within the bounds.
+fn apply_tuning((centre, spread): (f32, f32), (centre_by, spread_by): (f32, f32)) -> (f32, f32) {
+    (
+        (centre + centre_by).clamp(CENTRE_BOUNDS.0, CENTRE_BOUNDS.1),
+        (spread + spread_by).clamp(SPREAD_BOUNDS.0, SPREAD_BOUNDS.1),
+    )
+}
+
+pub fn feedback_buttons<'a>(
+    row: &'a mut CreateActionRow,
+    prefix: &str,
+    i18n: &LocaleAccess<Localizer<RikaLocale, RikaLocalizer>>,
+) -> &'a mut CreateActionRow {
+    for feedback in RecommendationFeedback::iter() {
+        row.create_button(|b| {
+            b.custom_id(format!("{prefix}{feedback}"))
+                .label(feedback.label(i18n))
+                .style(feedback.style())
+        });
+    }
+
+    row
+}
+
+/// Per axis multipliers of the centre and spread of the user's skill window.
+pub async fn fetch_tuning(
+    db: &Pool<MySql>,
+    osu_id: u32,
+    mode: OsuMode,
+) -> Result<HashMap<String, (f32, f32)>, sqlx::Error> {
+    #[derive(sqlx::FromRow)]
+    struct Tuning {
+        axis: String,
+        centre: f32,
+        spread: f32,
+    }
+
+    let tuning: Vec<Tuning> = sqlx::query_as(
+        "
+        SELECT axis, centre, spread FROM recommendation_tuning
+        WHERE osu_user_id = ? AND mode = ?
+        ",
+    )
+    .bind(osu_id)
+    .bind(mode as i16)
+    .fetch_all(db)
+    .await?;
+
+    Ok(tuning
+        .into_iter()
+        .map(|t| (t.axis, (t.centre, t.spread)))
+        .collect())
+}
+
+pub async fn store_feedback(
+    db: &Pool<MySql>,
+    osu_id: u32,
+    mode: OsuMode,
+    recommendation: &OsuScore,
+    feedback: RecommendationFeedback,
+) -> Result<(), sqlx::Error> {
+    let axes = mode_axes(mode);
+    let mode_name = mode.to_string().to_lowercase();
+
+    let performance = sqlx::query(&format!(
+        "SELECT {} FROM {mode_name}_performance WHERE score_id = ?",
+        axes.join(", ")
+    ))
+    .bind(recommendation.id)
+    .fetch_optional(db)
+    .await?;
+
+    let axis_values = axes
+        .iter()
+        .map(|axis| {
+            let value = performance
+                .as_ref()
+                .and_then(|p| p.try_get::<f32, _>(*axis).ok())
+                .unwrap_or(1.0);
+
+            (*axis, value)
+        })
+        .collect::<Vec<_>>();
+
+    let total: f32 = axis_values.iter().map(|(.., value)| value).sum();
+
+    let mut tx = db.begin().await?;
+
+    let previous = sqlx::query_scalar!(
+        "
+        SELECT kind FROM recommendation_feedback
+        WHERE osu_user_id = ? AND map_id = ? AND mode = ?
+        FOR UPDATE
+        ",
+        &osu_id,
+        &recommendation.map_id,
+        &recommendation.mode
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .and_then(|kind| RecommendationFeedback::from_str(&kind).ok());
+
+    // Clicking the same button again changes nothing, and changing one's mind swaps the tuning
+    // of the previous feedback for the new one instead of stacking them.
+    if previous == Some(feedback) {
+        return Ok(());
+    }
+
+    sqlx::query!(
+        "
+        INSERT INTO recommendation_feedback (osu_user_id, map_id, mode, mapper_id, kind)
+        VALUES (?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            kind = VALUES(kind)
+        ",
+        &osu_id,
+        &recommendation.map_id,
+        &recommendation.mode,
+        &recommendation.mapper_id,
+        &feedback.to_string()
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Locked so feedback on another map can't tune the same axes in between.
+    let current_tuning: HashMap<String, (f32, f32)> = sqlx::query_as::<_, (String, f32, f32)>(
+        "
+        SELECT axis, centre, spread FROM recommendation_tuning
+        WHERE osu_user_id = ? AND mode = ?
+        FOR UPDATE
+        ",
+    )
+    .bind(osu_id)
+    .bind(recommendation.mode)
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .map(|(axis, centre, spread)| (axis, (centre, spread)))
+    .collect();
+
+    for (axis, value) in axis_values {
+        let share = if total > 0.0 { value / total } else { 0.0 };
+
+        let current = current_tuning.get(axis).copied().unwrap_or((1.0, 1.0));
+        let (centre, spread) = apply_tuning(current, feedback.replacing(previous, share));
+
+        sqlx::query!(
+            "
+            INSERT INTO recommendation_tuning (osu_user_id, mode, axis, centre, spread)
+            VALUES (?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                centre = VALUES(centre),
+                spread = VALUES(spread)
+            ",
+            &osu_id,
+            &recommendation.mode,
+            &axis,
+            &centre,
+            &spread
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_tuning, RecommendationFeedback, CENTRE_BOUNDS, CENTRE_STEP, SPREAD_BOUNDS,
+        SPREAD_STEP,
+    };
+
+    fn close(a: (f32, f32), b: (f32, f32)) -> bool {
+        (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6
+    }
+
+    #[test]
+    fn feedback_moves_the_default_window() {
+        let tuned = |feedback: RecommendationFeedback| {
+            apply_tuning((1.0, 1.0), feedback.replacing(None, 1.0))
+        };
+
+        assert!(close(
+            tuned(RecommendationFeedback::TooEasy),
+            (1.0 + CENTRE_STEP, 1.0)
+        ));
+        assert!(close(
+            tuned(RecommendationFeedback::TooHard),
+            (1.0 - CENTRE_STEP, 1.0)
+        ));
+        assert!(close(
+            tuned(RecommendationFeedback::PlayedIt),
+            (1.0, 1.0 - SPREAD_STEP)
+        ));
+        assert!(close(
+            tuned(RecommendationFeedback::NotMyStyle),
+            (1.0, 1.0 + SPREAD_STEP)
+        ));
+    }
+
+    #[test]
+    fn repeated_feedback_stops_at_the_bounds() {
+        let repeated = |feedback: RecommendationFeedback| {
+            (0..100).fold((1.0, 1.0), |tuning, _| {
+                apply_tuning(tuning, feedback.replacing(None, 1.0))
+            })
+        };
+
+        assert_eq!(repeated(RecommendationFeedback::TooEasy).0, CENTRE_BOUNDS.1);
+        assert_eq!(repeated(RecommendationFeedback::TooHard).0, CENTRE_BOUNDS.0);
+        assert_eq!(
+            repeated(RecommendationFeedback::PlayedIt).1,
+            SPREAD_BOUNDS.0
+        );
+        assert_eq!(
+            repeated(RecommendationFeedback::NotMyStyle).1,
+            SPREAD_BOUNDS.1
+        );
+    }
+
+    #[test]
+    fn changing_feedback_undoes_the_previous_one() {
+        let too_hard = apply_tuning(
+            (1.0, 1.0),
+            RecommendationFeedback::TooHard.replacing(None, 0.5),
+        );
+        let too_easy = apply_tuning(
+            too_hard,
+            RecommendationFeedback::TooEasy.replacing(Some(RecommendationFeedback::TooHard), 0.5),
+        );
+
+        assert!(close(too_easy, (1.0 + CENTRE_STEP * 0.5, 1.0)));
+    }
+
+    #[test]
+    fn out_of_bounds_tuning_is_pulled_back() {
+        assert_eq!(
+            apply_tuning((3.0, 0.0), (0.0, 0.0)),
+            (CENTRE_BOUNDS.1, SPREAD_BOUNDS.0)
+        );
+    }
+}
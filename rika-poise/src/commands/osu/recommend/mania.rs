@@ -1,14 +1,13 @@
-use super::{get_weighter, mid_interval};
-use crate::{
-    commands::osu::recommend::query_recommendation,
-    utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
+use super::{
+    get_weighter, mid_interval,
+    strategy::{apply_strategy, RecommendStrategy, DEFAULT_PUSH},
+    ModsFilter, RecommendExclusion, RecommendationQuery,
 };
 use anyhow::anyhow;
 use lexicon::t_prefix;
 use paste::paste;
-use rika_model::{rika_cord, SharedRika};
+use rika_model::{osu::submit::SubmittableMode, rika_cord, SharedRika};
 use roricon::RoriconTrait;
-use rosu_v2::prelude::GameMods;
 
 use crate::{
     commands::{
@@ -21,17 +20,52 @@ use crate::{
 };
 
 #[poise::command(slash_command)]
-pub async fn mania(ctx: rika_cord::Context<'_>, range: Option<f32>) -> CommandReturn {
+pub async fn mania(
+    ctx: rika_cord::Context<'_>,
+    range: Option<f32>,
+    exclude: Option<RecommendExclusion>,
+    #[description = "Mods the map must be played with, like HDDT"] include_mods: Option<String>,
+    #[description = "Mods the map must not be played with"] exclude_mods: Option<String>,
+    #[description = "The exact mods the map must be played with, NM for no mods"]
+    exact_mods: Option<String>,
+    #[description = "How many maps to recommend"]
+    #[min = 1]
+    #[max = 25]
+    count: Option<u8>,
+    #[description = "How to pick the maps, comfort by default"] strategy: Option<RecommendStrategy>,
+    #[description = "How much harder the push strategy goes on your weakest skill, 0.15 by default"]
+    #[min = 0.0]
+    #[max = 1.0]
+    push_by: Option<f32>,
+) -> CommandReturn {
     let SharedRika { db, .. } = ctx.data().shared.as_ref();
 
-    init_recommendation!($, db, ctx, range, Mania);
+    let mods = ModsFilter::parse(&ctx.i18n(), include_mods, exclude_mods, exact_mods)?;
+
+    init_recommendation!($, db, ctx, range, Mania, osu_id);
 
     let (min_diff, max_diff) = apply_weight!(difficulty);
 
-    let recommendation =
-        query_recommendation(db, "mania", vec![("difficulty", (min_diff, max_diff))]);
+    let mut recommendation_query = RecommendationQuery {
+        osu_id,
+        mode: SubmittableMode::Mania,
+        overall: weigh!(overall),
+        values: vec![("difficulty", (min_diff, max_diff))],
+        mods,
+        exclusion: exclude.unwrap_or_default(),
+        cooldown_hours: ctx.data().config.recommendation_cooldown_hours,
+        strategy: RecommendStrategy::default(),
+        seed: ctx.id(),
+    };
+
+    let explanation = apply_strategy(
+        &ctx.i18n(),
+        &mut recommendation_query,
+        strategy.unwrap_or_default(),
+        push_by.unwrap_or(DEFAULT_PUSH),
+    );
 
-    reply_recommendation!(ctx, recommendation);
+    reply_recommendation!(ctx, db, recommendation_query, count, explanation, Mania);
 
     Ok(())
 }
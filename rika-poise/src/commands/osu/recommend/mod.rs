@@ -1,15 +1,37 @@
-use crate::{commands::CommandReturn, models::osu_score::OsuScore};
+use std::str::FromStr;
+
+use crate::{
+    commands::{osu::OsuMode, CommandReturn},
+    models::osu_score::OsuScore,
+    utils::markdown::mono,
+};
+use anyhow::anyhow;
+use lexicon::{t_prefix, LocaleAccess, Localizer};
 use num_traits::Float;
-use poise::command;
+use poise::{command, ChoiceParameter};
+use rand::{rngs::StdRng, seq::IteratorRandom, SeedableRng};
 
+pub mod feedback;
 mod mania;
 mod osu;
+pub mod reply;
+pub mod strategy;
 mod taiko;
 
 use mania::mania;
 use osu::osu;
-use rika_model::rika_cord;
-use sqlx::{MySql, Pool, QueryBuilder};
+pub use rika_model::osu::skills::get_weighter;
+use rika_model::{
+    i18n::{rika_localizer::RikaLocalizer, RikaLocale},
+    osu::{
+        skills::{self, SkillVector},
+        submit::SubmittableMode,
+    },
+    rika_cord,
+};
+use rosu_v2::prelude::{GameMode, GameMods};
+use sqlx::{FromRow, MySql, Pool, QueryBuilder, Row};
+use strategy::RecommendStrategy;
 use taiko::taiko;
 
 #[command(slash_command, subcommands("osu", "taiko", "mania"))]
@@ -17,35 +39,68 @@ pub async fn recommend(_ctx: rika_cord::Context<'_>) -> CommandReturn {
     Ok(())
 }
 
-pub fn get_weighter<T>(vec: Vec<T>) -> impl Fn(for<'a> fn(&'a T) -> f32) -> f32 {
-    move |f: fn(&T) -> f32| {
-        let (pp_sum, weight) = vec
-            .iter()
-            .map(f)
-            .enumerate()
-            .map(|(i, value)| (value, 0.95f32.powi(i as i32)))
-            .map(|(value, weight_by)| (value * weight_by, weight_by))
-            .fold((0f32, 0f32), |(pp_sum, weight), (value, weight_by)| {
-                (pp_sum + value, weight + weight_by)
-            });
-
-        pp_sum / weight
-    }
-}
+/// How wide the skill windows are when neither the user nor the guild picked a range.
+pub const DEFAULT_RECOMMEND_RANGE: f32 = 0.3;
 
 pub fn mid_interval<F: Float>(x: F, delta: F) -> (F, F) {
     let d = delta / F::from(2).unwrap();
     (x * (F::one() - d), x * (F::one() + d))
 }
 
+/// The skill axes stored in the performance table of each mode.
+pub fn mode_axes(mode: OsuMode) -> &'static [&'static str] {
+    mode.submittable()
+        .map(|mode| mode.axes())
+        .unwrap_or_default()
+}
+
+/// The localized name of a skill axis, or of the overall pp.
+pub fn axis_label(i18n: &LocaleAccess<Localizer<RikaLocale, RikaLocalizer>>, axis: &str) -> String {
+    t_prefix!($, i18n.osu.recommend.axis);
+
+    match axis {
+        "aim" => t!(aim),
+        "speed" => t!(speed),
+        "accuracy" => t!(accuracy),
+        "flashlight" => t!(flashlight),
+        "difficulty" => t!(difficulty),
+        "overall" => t!(overall),
+        _ => return axis.to_string(),
+    }
+    .clone()
+}
+
+/// The weighted overall pp and value of every skill axis of the user on the given mode.
+pub async fn weighted_skills(
+    db: &Pool<MySql>,
+    osu_id: u32,
+    mode: OsuMode,
+) -> Result<SkillVector, rika_cord::Error> {
+    let skills = skills::weighted_skills(db, osu_id, mode.submittable()?).await?;
+
+    Ok(skills.ok_or(rika_cord::OsuError::RequiresSubmission)?)
+}
+
 #[macro_export]
 macro_rules! create_weighter {
-    ($performance_values:expr, $range:expr) => {
+    ($performance_values:expr, $range:expr, $tuning:expr) => {
         let weight_to = get_weighter($performance_values);
+        let tuning = $tuning;
+
+        macro_rules! weigh {
+            ($field:ident) => {{
+                weight_to(|v| v.$field)
+            }};
+        }
 
         macro_rules! apply_weight {
             ($field:ident) => {{
-                mid_interval(weight_to(|v| v.$field), $range)
+                let (centre, spread) = tuning
+                    .get(stringify!($field))
+                    .copied()
+                    .unwrap_or((1.0, 1.0));
+
+                mid_interval(weight_to(|v| v.$field) * centre, $range * spread)
             }};
         }
     };
@@ -59,12 +114,13 @@ macro_rules! fetch_performance {
                 "
                 SELECT pp.* FROM osu_score s
                 JOIN {}_performance pp ON s.id = pp.score_id
-                WHERE osu_user_id = ?
+                WHERE s.osu_user_id = ? AND s.mode = ?
                 ORDER BY pp.overall DESC
                 ",
                 OsuMode::$mode.to_string().to_lowercase()
             ))
             .bind($osu_id)
+            .bind(OsuMode::$mode as i16)
             .fetch_all($db)
             .await?;
 
@@ -79,56 +135,343 @@ macro_rules! fetch_performance {
 
 #[macro_export]
 macro_rules! reply_recommendation {
-    ($ctx:expr, $recommendation:expr) => {
-        let recommendation = $recommendation
-            .await
-            .map_err(|_| anyhow!(t!(not_found).clone()))?;
+    ($ctx:expr, $db:expr, $recommendation_query:expr, $count:expr, $explanation:expr, $mode:ident) => {
+        let recommendation_query = $recommendation_query;
+        let osu_id = recommendation_query.osu_id;
+        let count = $count.unwrap_or(1) as usize;
 
-        let beatmap_link = format!("https://osu.ppy.sh/b/{}", recommendation.map_id);
-        let displayable_mods = GameMods::try_from(recommendation.mods)?;
+        let recommendations = $crate::commands::osu::recommend::pick_recommendations(
+            $db,
+            recommendation_query,
+            count,
+        )
+        .await?;
 
-        let content = t!(recommendation).r((beatmap_link, mono(displayable_mods.to_string())));
+        if recommendations.is_empty() {
+            return Err(anyhow!(t!(not_found).clone()))?;
+        }
 
-        $ctx.say(cool_text(RikaMoji::Ok, &content)).await?;
+        $crate::commands::osu::recommend::reply::reply_recommendations(
+            $ctx,
+            osu_id,
+            OsuMode::$mode,
+            recommendations,
+            $explanation,
+        )
+        .await?;
     };
 }
 
 #[macro_export]
 macro_rules! init_recommendation {
-    ($dollar:tt, $db:expr, $ctx:expr, $range:expr, $mode:ident) => {
+    ($dollar:tt, $db:expr, $ctx:expr, $range:expr, $mode:ident, $osu_id:ident) => {
         let i18n = $ctx.i18n();
         t_prefix!($dollar, i18n.osu.recommend);
 
-        let range = $range.unwrap_or(0.3);
-        let (.., osu_id) = $ctx.linked_osu_user().await?;
+        let range = $ctx.recommend_range_or_default($range).await?;
+        let (.., $osu_id) = $ctx.linked_osu_user().await?;
 
-        create_weighter!(fetch_performance!($mode, osu_id, $db), range);
+        create_weighter!(
+            fetch_performance!($mode, $osu_id, $db),
+            range,
+            $crate::commands::osu::recommend::feedback::fetch_tuning($db, $osu_id, OsuMode::$mode)
+                .await?
+        );
     };
 }
 
-async fn query_recommendation<'a>(
+#[derive(ChoiceParameter, Default, Clone, Copy)]
+pub enum RecommendExclusion {
+    #[default]
+    #[name = "maps"]
+    Maps,
+
+    #[name = "maps with the same mods"]
+    MapsWithMods,
+}
+
+/// The stored mods of a score with the mods that nightcore and perfect imply added, like parsing
+/// `GameMods` does, since scores may carry NC and PF with or without DT and SD.
+const IMPLIED_MODS_SQL: &str = "(s.mods | IF(s.mods & 512, 64, 0) | IF(s.mods & 16384, 32, 0))";
+
+/// Every acronym `GameMods::from_str` accepts, listed back when a mods filter fails to parse.
+const VALID_MOD_ACRONYMS: &str =
+    "NM NF EZ TD HD HR SD DT RX HT NC FL SO AP PF FI RD CN TP 1K–9K CP KC MR V2";
+
+#[derive(Default)]
+pub struct ModsFilter {
+    pub include: Option<GameMods>,
+    pub exclude: Option<GameMods>,
+    pub exact: Option<GameMods>,
+}
+
+impl ModsFilter {
+    pub fn parse(
+        i18n: &LocaleAccess<Localizer<RikaLocale, RikaLocalizer>>,
+        include: Option<String>,
+        exclude: Option<String>,
+        exact: Option<String>,
+    ) -> Result<Self, rika_cord::Error> {
+        t_prefix!($, i18n.osu.recommend);
+
+        let parse = |mods: Option<String>| {
+            mods.map(|mods| {
+                GameMods::from_str(&mods).map_err(|_| {
+                    anyhow!(t!(invalid_mods).r((mono(&mods), mono(VALID_MOD_ACRONYMS))))
+                })
+            })
+            .transpose()
+        };
+
+        Ok(Self {
+            include: parse(include)?,
+            exclude: parse(exclude)?,
+            exact: parse(exact)?,
+        })
+    }
+}
+
+pub struct RecommendationQuery<'a> {
+    pub osu_id: u32,
+    pub mode: SubmittableMode,
+    pub overall: f32,
+    pub values: Vec<(&'a str, (f32, f32))>,
+    pub mods: ModsFilter,
+    pub exclusion: RecommendExclusion,
+    pub cooldown_hours: u32,
+    pub strategy: RecommendStrategy,
+    pub seed: u64,
+}
+
+/// How many of the closest candidates a single recommendation is randomly picked from.
+const RECOMMENDATION_NEIGHBOURS: usize = 10;
+
+/// How far from the user's weighted overall pp a candidate may be, this is only a coarse
+/// filter that lets the overall index discard most of the table before ranking.
+const OVERALL_BAND: f32 = 1.0;
+
+/// The minimum pp an axis is normalised by, so axes the user has no pp on still count.
+const MIN_AXIS_SCALE: f32 = 1.0;
+
+pub struct Recommendation {
+    pub score: OsuScore,
+    pub overall: f32,
+    pub performance: Vec<(String, f32)>,
+    pub distance: f64,
+}
+
+/// Turns a squared normalised distance into how close two skill vectors are, from 0 to 100.
+pub fn closeness(distance: f64) -> f64 {
+    100.0 / (1.0 + distance.sqrt())
+}
+
+impl Recommendation {
+    /// How close the recommendation is to the user's skills, from 0 to 100.
+    pub fn closeness(&self) -> f64 {
+        closeness(self.distance)
+    }
+}
+
+/// Ranks the scores closest to the given skill windows, skipping maps the user already
+/// played, was recently recommended or gave feedback on.
+///
+/// Every axis is normalised by half the width of its window, so the distance is measured in
+/// "windows" and no axis dominates just because it is worth more pp.
+pub async fn query_recommendations(
     pool: &Pool<MySql>,
-    mode: &'a str,
-    values: Vec<(&'a str, (f32, f32))>,
-) -> Result<OsuScore, sqlx::Error> {
-    let mut query = QueryBuilder::<MySql>::new(format!(
-        "
-        SELECT s.*
+    recommendation_query: &RecommendationQuery<'_>,
+    limit: usize,
+) -> Result<Vec<Recommendation>, sqlx::Error> {
+    let RecommendationQuery {
+        osu_id,
+        mode,
+        overall,
+        values,
+        mods,
+        exclusion,
+        cooldown_hours,
+        strategy,
+        seed,
+    } = recommendation_query;
+
+    let same_mods = |table: &str| match exclusion {
+        RecommendExclusion::Maps => String::new(),
+        RecommendExclusion::MapsWithMods => format!("AND {table}.mods = s.mods"),
+    };
+
+    let mut query = QueryBuilder::<MySql>::new("SELECT s.*, pp.*, (");
+
+    let mut distance = query.separated(" + ");
+
+    for (name, (min, max)) in values {
+        let centre = (min + max) / 2.0;
+        let scale = ((max - min) / 2.0).max(MIN_AXIS_SCALE);
+
+        distance.push(format!("POW((pp.{name} - "));
+        distance.push_bind_unseparated(centre);
+        distance.push_unseparated(") / ");
+        distance.push_bind_unseparated(scale);
+        distance.push_unseparated(", 2)");
+    }
+
+    query.push(format!(
+        ") AS distance
         FROM osu_score s
         JOIN {mode}_performance pp ON s.id = pp.score_id
-        WHERE
+        WHERE s.mode = "
+    ));
+
+    query.push_bind(GameMode::from(*mode) as i16).push(
+        " AND NOT EXISTS (
+            SELECT 1 FROM osu_score own
+            WHERE own.osu_user_id = ",
+    );
+
+    query.push_bind(osu_id).push(format!(
         "
+            AND own.mode = s.mode AND own.map_id = s.map_id {}
+        ) AND NOT EXISTS (
+            SELECT 1 FROM recommendation_log r
+            WHERE r.osu_user_id = ",
+        same_mods("own")
     ));
 
-    let mut separated = query.separated(" AND ");
+    query.push_bind(osu_id).push(format!(
+        "
+            AND r.mode = s.mode AND r.map_id = s.map_id {}
+            AND r.created_at > NOW() - INTERVAL ",
+        same_mods("r")
+    ));
 
-    for (name, (min, max)) in values {
-        separated.push(format!("pp.{name} BETWEEN "));
-        separated.push_bind_unseparated(min);
-        separated.push_bind(max);
+    query.push_bind(cooldown_hours).push(
+        " HOUR
+        ) AND NOT EXISTS (
+            SELECT 1 FROM recommendation_feedback f
+            WHERE f.osu_user_id = ",
+    );
+
+    query.push_bind(osu_id).push(
+        "
+            AND f.mode = s.mode
+            AND (f.map_id = s.map_id OR (f.kind = 'not_my_style' AND f.mapper_id = s.mapper_id))
+        ) AND pp.overall BETWEEN ",
+    );
+
+    let (min_overall, max_overall) = mid_interval(*overall, OVERALL_BAND);
+
+    query
+        .push_bind(min_overall)
+        .push(" AND ")
+        .push_bind(max_overall);
+
+    if let Some(include) = mods.include {
+        query
+            .push(format!(" AND ({IMPLIED_MODS_SQL} & "))
+            .push_bind(include.bits())
+            .push(") = ")
+            .push_bind(include.bits());
+    }
+
+    if let Some(exclude) = mods.exclude {
+        query
+            .push(format!(" AND ({IMPLIED_MODS_SQL} & "))
+            .push_bind(exclude.bits())
+            .push(") = 0");
+    }
+
+    if let Some(exact) = mods.exact {
+        query
+            .push(format!(" AND {IMPLIED_MODS_SQL} = "))
+            .push_bind(exact.bits());
+    }
+
+    if *strategy == RecommendStrategy::Farm {
+        // Scores stored before star ratings were have nothing to be compared by.
+        query.push(" AND s.stars IS NOT NULL");
+    }
+
+    query.push(" ORDER BY distance");
+
+    if *strategy == RecommendStrategy::Farm {
+        // Scores worth a lot of pp for their star rating rank as if they were closer.
+        query.push(" / (pp.overall / GREATEST(s.stars, 1))");
     }
 
-    query.push(" ORDER BY RAND() ");
+    query
+        .push(" ASC, RAND(")
+        .push_bind(seed)
+        .push(") LIMIT ")
+        .push_bind(limit as u64);
+
+    let rows = query.build().fetch_all(pool).await?;
+
+    rows.iter()
+        .map(|row| {
+            let performance = values
+                .iter()
+                .map(|(name, ..)| Ok((name.to_string(), row.try_get(*name)?)))
+                .collect::<Result<_, sqlx::Error>>()?;
 
-    query.build_query_as().fetch_one(pool).await
+            Ok(Recommendation {
+                score: OsuScore::from_row(row)?,
+                overall: row.try_get("overall")?,
+                performance,
+                distance: row.try_get("distance")?,
+            })
+        })
+        .collect()
+}
+
+pub async fn log_recommendation(
+    pool: &Pool<MySql>,
+    osu_id: u32,
+    recommendation: &OsuScore,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "
+        INSERT INTO recommendation_log (osu_user_id, map_id, mods, mode)
+        VALUES (?, ?, ?, ?)
+        ",
+        &osu_id,
+        &recommendation.map_id,
+        &recommendation.mods,
+        &recommendation.mode
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Randomly picks some of the closest scores to the given skill windows, logging them as
+/// recommended.
+pub async fn pick_recommendations(
+    pool: &Pool<MySql>,
+    recommendation_query: RecommendationQuery<'_>,
+    count: usize,
+) -> Result<Vec<Recommendation>, sqlx::Error> {
+    let neighbours = count.max(RECOMMENDATION_NEIGHBOURS);
+    let candidates = query_recommendations(pool, &recommendation_query, neighbours).await?;
+
+    let mut rng = StdRng::seed_from_u64(recommendation_query.seed);
+
+    let mut recommendations = candidates.into_iter().choose_multiple(&mut rng, count);
+    recommendations.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+    for recommendation in &recommendations {
+        log_recommendation(pool, recommendation_query.osu_id, &recommendation.score).await?;
+    }
+
+    Ok(recommendations)
+}
+
+/// Picks one of the closest scores to the given skill windows and logs it as recommended.
+pub async fn query_recommendation(
+    pool: &Pool<MySql>,
+    recommendation_query: RecommendationQuery<'_>,
+) -> Result<Recommendation, sqlx::Error> {
+    pick_recommendations(pool, recommendation_query, 1)
+        .await?
+        .pop()
+        .ok_or(sqlx::Error::RowNotFound)
 }
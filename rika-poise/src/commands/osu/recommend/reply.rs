@@ -0,0 +1,168 @@
+use itertools::Itertools;
+use lexicon::{t_prefix, LocaleAccess, Localizer};
+use poise::serenity_prelude::{Color, CreateComponents, CreateEmbed, InteractionResponseType};
+use rika_model::{
+    i18n::{rika_localizer::RikaLocalizer, RikaLocale},
+    rika_cord,
+};
+use roricon::RoriconTrait;
+use rosu_v2::prelude::GameMods;
+
+use crate::{
+    commands::{
+        osu::{
+            recommend::{
+                axis_label,
+                feedback::{feedback_buttons, store_feedback, RecommendationFeedback},
+                weighted_skills, Recommendation,
+            },
+            OsuMode,
+        },
+        CommandReturn,
+    },
+    utils::{emojis::RikaMoji, markdown::mono, paginate::Paginator},
+};
+
+/// One line per axis with its pp, followed by the overall pp.
+fn axis_lines<'a>(
+    i18n: &LocaleAccess<Localizer<RikaLocale, RikaLocalizer>>,
+    axes: impl Iterator<Item = (&'a str, f32)>,
+    overall: f32,
+) -> String {
+    axes.chain([("overall", overall)])
+        .map(|(axis, pp)| format!("{}: {}", axis_label(i18n, axis), mono(format!("{pp:.2}pp"))))
+        .join("\n")
+}
+
+fn recommendation_embed<'a>(
+    embed: &'a mut CreateEmbed,
+    i18n: &LocaleAccess<Localizer<RikaLocale, RikaLocalizer>>,
+    recommendations: &[Recommendation],
+    (overall, skills): &(f32, Vec<(&'static str, f32)>),
+    explanation: &str,
+    page: usize,
+) -> &'a mut CreateEmbed {
+    t_prefix!($, i18n.osu.recommend);
+
+    let recommendation = &recommendations[page];
+
+    let beatmap_link = format!("https://osu.ppy.sh/b/{}", recommendation.score.map_id);
+    let displayable_mods = GameMods::from_bits(recommendation.score.mods).unwrap_or_default();
+
+    let player_id = recommendation.score.osu_user_id;
+    let player_link = format!("[#{player_id}](https://osu.ppy.sh/users/{player_id})");
+
+    let candidate = recommendation
+        .performance
+        .iter()
+        .map(|(axis, pp)| (axis.as_str(), *pp));
+
+    embed
+        .color(Color::PURPLE)
+        .title(format!(
+            "{} {}",
+            RikaMoji::Ok,
+            t!(page).r((page + 1, recommendations.len()))
+        ))
+        .description(t!(recommendation).r((beatmap_link, mono(displayable_mods.to_string()))))
+        .field(
+            t!(reason.skills),
+            axis_lines(i18n, skills.iter().copied(), *overall),
+            true,
+        )
+        .field(
+            t!(reason.candidate),
+            axis_lines(i18n, candidate, recommendation.overall),
+            true,
+        )
+        .field(
+            t!(reason.player),
+            t!(reason.player_got).r((
+                player_link,
+                mono(format!("{:.2}pp", recommendation.overall)),
+            )),
+            false,
+        )
+        .field(
+            t!(closeness),
+            mono(format!("{:.1}%", recommendation.closeness())),
+            true,
+        )
+        .field(t!(strategy.title), explanation, true)
+}
+
+fn recommendation_components<'a>(
+    components: &'a mut CreateComponents,
+    i18n: &LocaleAccess<Localizer<RikaLocale, RikaLocalizer>>,
+    paginator: &Paginator,
+    feedback_prefix: &str,
+) -> &'a mut CreateComponents {
+    paginator
+        .navigation_row(components)
+        .create_action_row(|row| feedback_buttons(row, feedback_prefix, i18n))
+}
+
+/// Replies with a page for each recommendation, explaining it against the user's weighted
+/// skills and listening for navigation and feedback from the invoking user until the buttons
+/// time out.
+pub async fn reply_recommendations(
+    ctx: rika_cord::Context<'_>,
+    osu_id: u32,
+    mode: OsuMode,
+    recommendations: Vec<Recommendation>,
+    explanation: String,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.recommend.feedback);
+
+    let db = &ctx.data().shared.db;
+
+    let skills = weighted_skills(db, osu_id, mode).await?;
+
+    let mut paginator = Paginator::new(ctx, recommendations.len());
+    let feedback_prefix = format!("{}feedback_", paginator.prefix());
+
+    let reply = ctx
+        .send(|r| {
+            r.embed(|e| {
+                recommendation_embed(
+                    e,
+                    &i18n,
+                    &recommendations,
+                    &skills,
+                    &explanation,
+                    paginator.page(),
+                )
+            })
+            .components(|c| recommendation_components(c, &i18n, &paginator, &feedback_prefix))
+        })
+        .await?;
+
+    while let Some(press) = paginator
+        .next_press(ctx, |e, page| {
+            recommendation_embed(e, &i18n, &recommendations, &skills, &explanation, page)
+        })
+        .await?
+    {
+        let Some(feedback) =
+            RecommendationFeedback::from_custom_id(&feedback_prefix, &press.data.custom_id)
+        else {
+            continue;
+        };
+
+        let recommendation = &recommendations[paginator.page()].score;
+
+        store_feedback(db, osu_id, mode, recommendation, feedback).await?;
+
+        press
+            .create_interaction_response(ctx, |r| {
+                r.kind(InteractionResponseType::ChannelMessageWithSource)
+                    .interaction_response_data(|d| d.content(t!(thanks)).ephemeral(true))
+            })
+            .await?;
+    }
+
+    reply.edit(ctx, |r| r.components(|c| c)).await?;
+
+    Ok(())
+}
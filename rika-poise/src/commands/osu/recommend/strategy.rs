@@ -0,0 +1,76 @@
+use lexicon::{t_prefix, LocaleAccess, Localizer};
+use poise::ChoiceParameter;
+use rika_model::i18n::{rika_localizer::RikaLocalizer, RikaLocale};
+
+use super::RecommendationQuery;
+use crate::utils::markdown::mono;
+
+/// How much higher the window of the weakest axis goes when pushing, by default.
+pub const DEFAULT_PUSH: f32 = 0.15;
+
+/// The most the window of the weakest axis can be pushed by, anything past it is no longer a
+/// recommendation around the user's skill.
+pub const MAX_PUSH: f32 = 1.0;
+
+#[derive(ChoiceParameter, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RecommendStrategy {
+    /// Maps around the user's current skill.
+    #[default]
+    #[name = "comfort"]
+    Comfort,
+
+    /// Maps a bit harder than the user's current skill on their weakest axis.
+    #[name = "push"]
+    Push,
+
+    /// Maps whose scores give a lot of pp for their star rating.
+    #[name = "farm"]
+    Farm,
+}
+
+/// The axis the user has the least pp on, axes the user has no pp on at all are skipped since no
+/// push could move a window sitting at zero.
+fn weakest_axis(recommendation_query: &RecommendationQuery<'_>) -> Option<usize> {
+    recommendation_query
+        .values
+        .iter()
+        .enumerate()
+        .map(|(i, (.., (min, max)))| (i, (min + max) / 2.0))
+        .filter(|(.., centre)| *centre > 0.0)
+        .min_by(|(.., a), (.., b)| a.total_cmp(b))
+        .map(|(i, ..)| i)
+}
+
+/// Adjusts the query to the strategy, returning why the recommendations were picked.
+pub fn apply_strategy(
+    i18n: &LocaleAccess<Localizer<RikaLocale, RikaLocalizer>>,
+    recommendation_query: &mut RecommendationQuery<'_>,
+    strategy: RecommendStrategy,
+    push_by: f32,
+) -> String {
+    t_prefix!($, i18n.osu.recommend.strategy);
+
+    recommendation_query.strategy = strategy;
+
+    let push_by = push_by.clamp(0.0, MAX_PUSH);
+
+    let explanation = match strategy {
+        RecommendStrategy::Comfort => t!(comfort).clone(),
+        RecommendStrategy::Farm => t!(farm).clone(),
+        RecommendStrategy::Push => match weakest_axis(recommendation_query) {
+            Some(weakest) => {
+                let (name, (min, max)) = &mut recommendation_query.values[weakest];
+
+                *min *= 1.0 + push_by;
+                *max *= 1.0 + push_by;
+
+                let percentage = format!("{:.0}%", push_by * 100.0);
+
+                t!(push).r((mono(percentage), mono(name.to_string())))
+            }
+            None => t!(comfort).clone(),
+        },
+    };
+
+    explanation
+}
@@ -0,0 +1,167 @@
+use anyhow::anyhow;
+use itertools::Itertools;
+use lexicon::t_prefix;
+use poise::{serenity_prelude::Color, ChoiceParameter};
+use rika_model::{osu::skills::weighted_skills, rika_cord, SharedRika};
+use roricon::RoriconTrait;
+use sqlx::{MySql, QueryBuilder, Row};
+
+use crate::{
+    commands::{
+        osu::{linked_guild_members, recommend::closeness, OsuMode, RikaOsuContext},
+        CommandReturn,
+    },
+    utils::{emojis::RikaMoji, markdown::mono},
+};
+
+/// The minimum pp an axis is normalised by, so axes the user has no pp on still count.
+const MIN_AXIS_SCALE: f32 = 1.0;
+
+#[derive(ChoiceParameter, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RivalScope {
+    #[default]
+    #[name = "everyone"]
+    Everyone,
+
+    #[name = "my country"]
+    Country,
+
+    #[name = "this server"]
+    Guild,
+}
+
+/// Finds the players whose weighted skills are the closest to yours.
+#[poise::command(slash_command)]
+pub async fn rivals(
+    ctx: rika_cord::Context<'_>,
+    mode: Option<OsuMode>,
+    #[description = "Who to look for rivals among, everyone by default"] scope: Option<RivalScope>,
+    #[description = "How many rivals to list"]
+    #[min = 1]
+    #[max = 25]
+    count: Option<u8>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.rivals);
+
+    let SharedRika { db, rosu, .. } = ctx.data().shared.as_ref();
+
+    let mode = ctx.mode_or_default(mode).await?;
+    let submit_mode = mode.submittable()?;
+    let scope = scope.unwrap_or_default();
+
+    let (.., osu_id) = ctx.linked_osu_user().await?;
+
+    let (overall, skills) = weighted_skills(db, osu_id, submit_mode)
+        .await?
+        .ok_or(rika_cord::OsuError::RequiresSubmission)?;
+
+    let osu_user = rosu.user(osu_id).mode(mode.into()).await?;
+
+    let guild_osu_ids = match scope {
+        RivalScope::Guild => {
+            let guild_id = ctx
+                .guild_id()
+                .ok_or_else(|| anyhow!(t!(guild_only).clone()))?;
+
+            linked_guild_members(ctx, db, guild_id)
+                .await?
+                .into_iter()
+                .map(|(.., osu_id)| osu_id)
+                .collect_vec()
+        }
+        _ => vec![],
+    };
+
+    // The ranking holds a row per axis, so each one adds its own term to the user's distance.
+    let mut query = QueryBuilder::<MySql>::new("SELECT r.osu_user_id, u.username, SUM(CASE r.axis");
+
+    for (axis, value) in skills.iter().chain(&[("overall", overall)]) {
+        query
+            .push(format!(" WHEN '{axis}' THEN POW((r.value - "))
+            .push_bind(*value)
+            .push(") / ")
+            .push_bind(value.max(MIN_AXIS_SCALE))
+            .push(", 2)");
+    }
+
+    query
+        .push(
+            " END) AS distance
+            FROM skill_ranking r
+            JOIN osu_user u ON u.id = r.osu_user_id
+            WHERE r.mode = ",
+        )
+        .push_bind(mode as i16)
+        .push(" AND r.osu_user_id != ")
+        .push_bind(osu_id);
+
+    match scope {
+        RivalScope::Everyone => {}
+        RivalScope::Country => {
+            query
+                .push(" AND u.country_code = ")
+                .push_bind(osu_user.country_code.to_string());
+        }
+        RivalScope::Guild => {
+            if guild_osu_ids.is_empty() {
+                return Err(anyhow!(t!(empty).clone()))?;
+            }
+
+            query.push(" AND r.osu_user_id IN (");
+
+            let mut ids = query.separated(", ");
+            for guild_osu_id in &guild_osu_ids {
+                ids.push_bind(*guild_osu_id);
+            }
+
+            query.push(")");
+        }
+    }
+
+    // Users missing an axis would sum fewer terms and look closer than they are.
+    query
+        .push(" GROUP BY r.osu_user_id, u.username HAVING COUNT(*) = ")
+        .push_bind((skills.len() + 1) as u32)
+        .push(" ORDER BY distance ASC LIMIT ")
+        .push_bind(count.unwrap_or(10) as u32);
+
+    let rows = query.build().fetch_all(db).await?;
+
+    if rows.is_empty() {
+        return Err(anyhow!(t!(empty).clone()))?;
+    }
+
+    let rivals = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let rival_id: u32 = row.try_get("osu_user_id")?;
+            let username: Option<String> = row.try_get("username")?;
+            let distance: f64 = row.try_get("distance")?;
+
+            Ok(format!(
+                "{}. [{}](https://osu.ppy.sh/users/{rival_id}) {}",
+                i + 1,
+                username.unwrap_or_else(|| format!("#{rival_id}")),
+                mono(format!("{:.1}%", closeness(distance)))
+            ))
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()?
+        .join("\n");
+
+    ctx.send(|r| {
+        r.embed(|e| {
+            e.color(Color::PURPLE)
+                .title(format!(
+                    "{} {}",
+                    RikaMoji::Ok,
+                    t!(title).r(osu_user.username.to_string())
+                ))
+                .description(rivals)
+        })
+    })
+    .await?;
+
+    Ok(())
+}
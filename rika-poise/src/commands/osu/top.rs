@@ -0,0 +1,109 @@
+use anyhow::anyhow;
+use itertools::Itertools;
+use lexicon::t_prefix;
+use rika_model::{rika_cord, SharedRika};
+use roricon::RoriconTrait;
+use sqlx::{MySql, QueryBuilder, Row};
+
+use crate::{
+    commands::{
+        osu::{recommend::axis_label, OsuMode, RikaOsuContext, SkillAxis},
+        CommandReturn,
+    },
+    utils::{emojis::RikaMoji, markdown::mono, paginate::paginate_embed},
+};
+
+/// How many players are listed on each page.
+const TOP_PAGE_SIZE: usize = 10;
+
+/// How many players are listed at most.
+const TOP_LIMIT: u32 = 100;
+
+/// Ranks the scraped players by a single weighted skill, see /osu top-scores for your own scores.
+#[poise::command(slash_command)]
+pub async fn top(
+    ctx: rika_cord::Context<'_>,
+    mode: Option<OsuMode>,
+    #[description = "The skill to rank by, overall pp by default"] axis: Option<SkillAxis>,
+    #[description = "A country code like BR, everyone by default"] country: Option<String>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.top);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let mode = ctx.mode_or_default(mode).await?;
+    let submit_mode = mode.submittable()?;
+    let axis = axis.unwrap_or_default();
+    let axis_name = axis_label(&i18n, axis.column());
+
+    if !axis.is_in(submit_mode) {
+        return Err(anyhow!(i18n
+            .r(|v| &v.osu.leaderboard.invalid_axis)
+            .r(mono(axis_name))))?;
+    }
+
+    let country = country.map(|country| country.to_uppercase());
+
+    let mut query = QueryBuilder::<MySql>::new(
+        "
+        SELECT r.osu_user_id, r.value, u.username FROM skill_ranking r
+        JOIN osu_user u ON u.id = r.osu_user_id
+        WHERE r.mode = ",
+    );
+
+    query
+        .push_bind(mode as i16)
+        .push(" AND r.axis = ")
+        .push_bind(axis.column());
+
+    if let Some(country) = &country {
+        query.push(" AND u.country_code = ").push_bind(country);
+    }
+
+    query
+        .push(" ORDER BY r.value DESC LIMIT ")
+        .push_bind(TOP_LIMIT);
+
+    let rows = query.build().fetch_all(db).await?;
+
+    if rows.is_empty() {
+        return Err(anyhow!(t!(empty).clone()))?;
+    }
+
+    let lines = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let osu_id: u32 = row.try_get("osu_user_id")?;
+            let value: f32 = row.try_get("value")?;
+            let username: Option<String> = row.try_get("username")?;
+
+            Ok(format!(
+                "{}. [{}](https://osu.ppy.sh/users/{osu_id}) {}",
+                i + 1,
+                username.unwrap_or_else(|| format!("#{osu_id}")),
+                mono(format!("{value:.2}pp"))
+            ))
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()?;
+
+    let pages = lines
+        .chunks(TOP_PAGE_SIZE)
+        .map(|chunk| chunk.join("\n"))
+        .collect_vec();
+
+    let scope = match country {
+        Some(country) => mono(country),
+        None => t!(global).clone(),
+    };
+
+    paginate_embed(
+        ctx,
+        &format!("{} {}", RikaMoji::Ok, t!(title).r((axis_name, scope))),
+        &pages,
+    )
+    .await?;
+
+    Ok(())
+}
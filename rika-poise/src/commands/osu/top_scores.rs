@@ -0,0 +1,117 @@
+use anyhow::anyhow;
+use itertools::Itertools;
+use lexicon::t_prefix;
+use rika_model::{rika_cord, SharedRika};
+use roricon::RoriconTrait;
+use rosu_v2::prelude::GameMods;
+use sqlx::Row;
+
+use crate::{
+    commands::{
+        osu::{recommend::axis_label, OsuMode, RikaOsuContext, SkillAxis},
+        CommandReturn,
+    },
+    utils::{emojis::RikaMoji, markdown::mono, paginate::paginate_embed},
+};
+
+/// How many scores are listed on each page, each one takes two lines.
+const SCORES_PAGE_SIZE: usize = 5;
+
+/// Pages through your stored scores and their performance, /osu top ranks players instead.
+#[poise::command(slash_command, rename = "top-scores")]
+pub async fn top_scores(
+    ctx: rika_cord::Context<'_>,
+    mode: Option<OsuMode>,
+    #[description = "The skill to sort by, overall pp by default"] sort: Option<SkillAxis>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.top_scores);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    let mode = ctx.mode_or_default(mode).await?;
+    let submit_mode = mode.submittable()?;
+    let sort = sort.unwrap_or_default();
+    let sort_name = axis_label(&i18n, sort.column());
+
+    if !sort.is_in(submit_mode) {
+        return Err(anyhow!(i18n
+            .r(|v| &v.osu.leaderboard.invalid_axis)
+            .r(mono(sort_name))))?;
+    }
+
+    let (.., osu_id) = ctx.linked_osu_user().await?;
+
+    let axes = submit_mode.axes();
+
+    let rows = sqlx::query(&format!(
+        "
+        SELECT s.map_id, s.mods, pp.overall, pp.{} FROM osu_score s
+        JOIN {submit_mode}_performance pp ON pp.score_id = s.id
+        WHERE s.osu_user_id = ? AND s.mode = ?
+        ORDER BY pp.{} DESC
+        ",
+        axes.iter().join(", pp."),
+        sort.column()
+    ))
+    .bind(osu_id)
+    .bind(mode as i16)
+    .fetch_all(db)
+    .await?;
+
+    if rows.is_empty() {
+        return Err(anyhow!(t!(empty).clone()))?;
+    }
+
+    let lines = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let map_id: u32 = row.try_get("map_id")?;
+            let mods: u32 = row.try_get("mods")?;
+            let overall: f32 = row.try_get("overall")?;
+
+            let displayable_mods = GameMods::from_bits(mods).unwrap_or_default();
+
+            let breakdown = axes
+                .iter()
+                .map(|axis| {
+                    let pp: f32 = row.try_get(*axis)?;
+                    Ok(format!(
+                        "{} {}",
+                        axis_label(&i18n, axis),
+                        mono(format!("{pp:.2}"))
+                    ))
+                })
+                .collect::<Result<Vec<_>, sqlx::Error>>()?
+                .join(" · ");
+
+            Ok(format!(
+                "{}. [#{map_id}](https://osu.ppy.sh/b/{map_id}) {} {}\n{breakdown}",
+                i + 1,
+                mono(displayable_mods.to_string()),
+                mono(format!("{overall:.2}pp"))
+            ))
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()?;
+
+    let pages = lines
+        .chunks(SCORES_PAGE_SIZE)
+        .map(|chunk| chunk.join("\n\n"))
+        .collect_vec();
+
+    let username = sqlx::query_scalar!("SELECT username FROM osu_user WHERE id = ?", &osu_id)
+        .fetch_optional(db)
+        .await?
+        .flatten()
+        .unwrap_or_else(|| format!("#{osu_id}"));
+
+    paginate_embed(
+        ctx,
+        &format!("{} {}", RikaMoji::Ok, t!(title).r((username, sort_name))),
+        &pages,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use lexicon::t_prefix;
+use rika_model::{osu::link::unlink as unlink_osu_user, rika_cord, SharedRika};
+use roricon::RoriconTrait;
+
+use crate::{
+    commands::{osu::RikaOsuContext, CommandReturn},
+    utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
+};
+
+/// Removes the link between your Discord account and your osu! account.
+#[poise::command(slash_command)]
+pub async fn unlink(ctx: rika_cord::Context<'_>) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.osu.unlink);
+
+    let SharedRika { db, .. } = ctx.data().shared.as_ref();
+
+    // Links made before verification existed can be removed without verifying them first.
+    let (.., osu_id) = ctx.claimed_osu_user().await?;
+
+    let username = sqlx::query_scalar!("SELECT username FROM osu_user WHERE id = ?", &osu_id)
+        .fetch_optional(db)
+        .await?
+        .flatten()
+        .unwrap_or_else(|| format!("#{osu_id}"));
+
+    unlink_osu_user(db, &ctx.author().id.to_string(), osu_id).await?;
+
+    ctx.say(cool_text(RikaMoji::Ok, &t!(unlinked).r(mono(username))))
+        .await?;
+
+    Ok(())
+}
@@ -1,12 +1,14 @@
 pub mod register;
+pub mod scraper;
 
 use poise::command;
 use register::register;
 use rika_model::rika_cord;
+use scraper::scraper;
 
 use crate::commands::CommandReturn;
 
-#[command(slash_command, subcommands("register"))]
+#[command(slash_command, subcommands("register", "scraper"))]
 pub async fn owner(_ctx: rika_cord::Context<'_>) -> CommandReturn {
     Ok(())
 }
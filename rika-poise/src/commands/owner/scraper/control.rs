@@ -0,0 +1,39 @@
+use lexicon::t_prefix;
+use rika_model::rika_cord;
+
+use crate::{
+    commands::CommandReturn,
+    utils::{emojis::RikaMoji, replies::cool_text},
+};
+
+/// Pauses the scraper before its next ranking page or user (Owner Only)
+#[poise::command(owners_only, slash_command)]
+pub async fn pause(ctx: rika_cord::Context<'_>) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.owner.scraper);
+
+    let reply = match ctx.data().shared.scraper.pause() {
+        true => t!(paused),
+        false => t!(already_paused),
+    };
+
+    ctx.say(cool_text(RikaMoji::Ok, reply)).await?;
+
+    Ok(())
+}
+
+/// Resumes the scraper where it was paused (Owner Only)
+#[poise::command(owners_only, slash_command)]
+pub async fn resume(ctx: rika_cord::Context<'_>) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.owner.scraper);
+
+    let reply = match ctx.data().shared.scraper.resume() {
+        true => t!(resumed),
+        false => t!(already_running),
+    };
+
+    ctx.say(cool_text(RikaMoji::Ok, reply)).await?;
+
+    Ok(())
+}
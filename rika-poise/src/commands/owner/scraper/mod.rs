@@ -0,0 +1,20 @@
+use crate::commands::CommandReturn;
+use poise::command;
+
+mod control;
+mod settings;
+mod status;
+
+use control::{pause, resume};
+use rika_model::rika_cord;
+use settings::{set_country, set_modes};
+use status::status;
+
+#[command(
+    slash_command,
+    owners_only,
+    subcommands("status", "pause", "resume", "set_country", "set_modes")
+)]
+pub async fn scraper(_ctx: rika_cord::Context<'_>) -> CommandReturn {
+    Ok(())
+}
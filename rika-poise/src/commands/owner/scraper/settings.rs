@@ -0,0 +1,77 @@
+use anyhow::anyhow;
+use itertools::Itertools;
+use lexicon::t_prefix;
+use rika_model::rika_cord;
+use roricon::RoriconTrait;
+
+use crate::{
+    commands::{osu::OsuMode, CommandReturn},
+    utils::{emojis::RikaMoji, markdown::mono, replies::cool_text},
+};
+
+/// Sets the countries whose rankings are scraped from the next pass on (Owner Only)
+#[poise::command(owners_only, slash_command, rename = "set-country")]
+pub async fn set_country(
+    ctx: rika_cord::Context<'_>,
+    #[description = "Country codes like BR,US, global if empty"] countries: Option<String>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.owner.scraper);
+
+    let countries = countries
+        .iter()
+        .flat_map(|countries| countries.split(','))
+        .map(|country| country.trim().to_uppercase())
+        .filter(|country| !country.is_empty())
+        .unique()
+        .collect_vec();
+
+    if let Some(invalid) = countries
+        .iter()
+        .find(|country| country.len() != 2 || !country.chars().all(|c| c.is_ascii_alphabetic()))
+    {
+        return Err(anyhow!(t!(invalid_country).r(mono(invalid))))?;
+    }
+
+    let shown = match countries.is_empty() {
+        true => i18n.r(|v| &v.owner.scraper.status.world).clone(),
+        false => countries.join(", "),
+    };
+
+    ctx.data().shared.scraper.set_countries(countries).await;
+
+    ctx.say(cool_text(RikaMoji::Ok, &t!(countries_set).r(mono(shown))))
+        .await?;
+
+    Ok(())
+}
+
+/// Sets the modes whose rankings are scraped from the next pass on (Owner Only)
+#[poise::command(owners_only, slash_command, rename = "set-modes")]
+pub async fn set_modes(
+    ctx: rika_cord::Context<'_>,
+    mode: OsuMode,
+    second_mode: Option<OsuMode>,
+    third_mode: Option<OsuMode>,
+) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.owner.scraper);
+
+    let modes = [Some(mode), second_mode, third_mode]
+        .into_iter()
+        .flatten()
+        .map(OsuMode::submittable)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unique_by(|mode| mode.to_string())
+        .collect_vec();
+
+    let shown = modes.iter().join(", ");
+
+    ctx.data().shared.scraper.set_modes(modes).await;
+
+    ctx.say(cool_text(RikaMoji::Ok, &t!(modes_set).r(mono(shown))))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use lexicon::t_prefix;
+use poise::serenity_prelude::Color;
+use rika_model::rika_cord;
+use roricon::RoriconTrait;
+use sqlx::types::time::OffsetDateTime;
+
+use crate::{
+    commands::CommandReturn,
+    utils::{emojis::RikaMoji, markdown::mono},
+};
+
+/// Shows what the scraper is doing and when its pass should finish (Owner Only)
+#[poise::command(owners_only, slash_command)]
+pub async fn status(ctx: rika_cord::Context<'_>) -> CommandReturn {
+    let i18n = ctx.i18n();
+    t_prefix!($, i18n.owner.scraper.status);
+
+    let handle = &ctx.data().shared.scraper;
+    let status = handle.status().await;
+
+    let state = match handle.is_paused() {
+        true => t!(paused).clone(),
+        false => t!(running).clone(),
+    };
+
+    let ranking = match status.mode {
+        Some(mode) => t!(ranking_of).r((
+            mode.to_string(),
+            status.country.clone().unwrap_or_else(|| t!(world).clone()),
+        )),
+        None => t!(idle).clone(),
+    };
+
+    let page = status
+        .page
+        .map(|page| mono(page.to_string()))
+        .unwrap_or_else(|| t!(unknown).clone());
+
+    let eta = status
+        .eta()
+        .map(|eta| {
+            let finishes_at = OffsetDateTime::now_utc() + eta;
+            format!("<t:{}:R>", finishes_at.unix_timestamp())
+        })
+        .unwrap_or_else(|| t!(unknown).clone());
+
+    ctx.send(|r| {
+        r.embed(|e| {
+            e.color(Color::PURPLE)
+                .title(format!("{} {}", RikaMoji::Ok, t!(title)))
+                .field(t!(state), state, true)
+                .field(t!(ranking), ranking, true)
+                .field(t!(page), page, true)
+                .field(
+                    t!(processed),
+                    mono(status.users_processed.to_string()),
+                    true,
+                )
+                .field(t!(errors), mono(status.errors.to_string()), true)
+                .field(t!(eta), eta, true)
+        })
+    })
+    .await?;
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use crate::utils::{emojis::RikaMoji, replies::cool_text};
 use log::error;
-use rika_model::rika_cord;
+use rika_model::{osu::submit::SubmissionError, rika_cord};
 
 pub async fn on_error(
     error: poise::FrameworkError<'_, Arc<rika_cord::Data>, rika_cord::Error>,
@@ -30,12 +30,21 @@ pub async fn on_error(
             match error {
                 rika_cord::Error::Anyhow(e) => handle!(e),
                 rika_cord::Error::RikaOsu(e) => handle!(e),
+                rika_cord::Error::Submission(e @ SubmissionError::OptedOut) => handle!(e),
                 e => handle!(
                     e,
                     "Something unexpected happened while executing this command."
                 ),
             }
         }
+        poise::FrameworkError::CommandCheckFailed {
+            error: Some(rika_cord::Error::Anyhow(error)),
+            ctx,
+        } => {
+            let content = cool_text(RikaMoji::X, &error.to_string());
+
+            ctx.send(|r| r.content(content).ephemeral(true)).await?;
+        }
         e => poise::builtins::on_error(e)
             .await
             .unwrap_or_else(|e| error!("{e:?}")),
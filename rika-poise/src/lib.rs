@@ -4,11 +4,19 @@ pub mod commands;
 pub mod error;
 pub mod models;
 pub mod setup;
+pub mod tracker;
 pub mod utils;
 
 use std::sync::Arc;
 
-use commands::{math::math, osu::osu, owner::owner, rate::rate, user::user};
+use commands::{
+    config::{command_check, config},
+    math::math,
+    osu::osu,
+    owner::owner,
+    rate::rate,
+    user::user,
+};
 use log::error;
 
 use poise::{futures_util::TryFutureExt, serenity_prelude::GatewayIntents, FrameworkOptions};
@@ -24,7 +32,7 @@ pub async fn run(
 
     let config = envy::from_env::<rika_cord::Config>()?;
 
-    let mut commands = vec![user(), owner(), math(), rate(), osu()];
+    let mut commands = vec![user(), owner(), math(), rate(), osu(), config()];
 
     apply_translations(&mut commands, &shared_rika.locales);
 
@@ -32,6 +40,7 @@ pub async fn run(
         .options(FrameworkOptions {
             commands,
             on_error: |err| Box::pin(error::on_error(err).unwrap_or_else(|e| error!("{}", e))),
+            command_check: Some(|ctx| Box::pin(command_check(ctx))),
             ..Default::default()
         })
         .token(&config.bot_token)
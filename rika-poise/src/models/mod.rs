@@ -1,3 +1,2 @@
-pub mod osu_user;
 pub mod osu_score;
-
+pub mod osu_user;
@@ -31,6 +31,7 @@ pub struct OsuScore {
     pub osu_user_id: u32,
     pub mods: u32,
     pub map_id: u32,
+    pub mapper_id: Option<u32>,
     pub created_at: OffsetDateTime,
     pub mode: i16,
 }
@@ -12,5 +12,38 @@ impl OsuUser {
             osu_id
         )
     }
-}
 
+    /// Creates the user, or refreshes the profile details of an existing one.
+    pub fn upsert_profile<'a>(
+        osu_id: &'a u32,
+        username: &'a str,
+        country_code: &'a str,
+    ) -> Query<'a, MySql, MySqlArguments> {
+        sqlx::query!(
+            "
+            INSERT INTO osu_user (id, username, country_code)
+            VALUES (?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                username = VALUES(username),
+                country_code = VALUES(country_code)
+            ",
+            osu_id,
+            username,
+            country_code
+        )
+    }
+
+    /// Creates the user if needed, opted out users are neither scraped nor submitted.
+    pub fn set_opted_out(osu_id: &u32, opted_out: bool) -> Query<'_, MySql, MySqlArguments> {
+        sqlx::query!(
+            "
+            INSERT INTO osu_user (id, opted_out)
+            VALUES (?, ?)
+            ON DUPLICATE KEY UPDATE
+                opted_out = VALUES(opted_out)
+            ",
+            osu_id,
+            opted_out
+        )
+    }
+}
@@ -0,0 +1,276 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use itertools::Itertools;
+use lexicon::{t_prefix, LocaleAccess, Localizer};
+use log::error;
+use poise::serenity_prelude::{self, ChannelId, Color, CreateEmbed, GuildId, Member};
+use rika_model::{
+    i18n::{rika_localizer::RikaLocalizer, RikaLocale},
+    osu::{
+        skills::{weighted_skills, SkillVector},
+        submit::{ScoreSubmitter, SubmissionError, SubmittableMode, TopPlay},
+    },
+    rika_cord, SharedRika,
+};
+use rosu_v2::prelude::GameMode;
+use tokio::time::sleep;
+
+use crate::{
+    commands::osu::{linked_guild_members, recommend::axis_label},
+    utils::{emojis::RikaMoji, markdown::mono},
+};
+
+/// How long to wait between the users of a check, so the osu! api is not hammered.
+const TRACKED_USER_DELAY: Duration = Duration::from_secs(1);
+
+/// Checks the linked members of every guild with announcements on for new top plays, forever.
+pub async fn track_top_plays(ctx: serenity_prelude::Context, data: Arc<rika_cord::Data>) {
+    let interval = Duration::from_secs(data.config.top_play_check_minutes as u64 * 60);
+
+    loop {
+        sleep(interval).await;
+
+        if let Err(e) = check_top_plays(&ctx, &data).await {
+            error!("{e:?}");
+        }
+    }
+}
+
+/// Where a new top play of a member is posted, and in which language.
+struct AnnouncementTarget {
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    locale: RikaLocale,
+    member: Member,
+    /// Plays set before the channel was picked are not announced.
+    since: i64,
+}
+
+async fn check_top_plays(
+    ctx: &serenity_prelude::Context,
+    data: &rika_cord::Data,
+) -> Result<(), rika_cord::Error> {
+    let SharedRika { db, .. } = data.shared.as_ref();
+
+    let guilds = sqlx::query!(
+        "
+        SELECT guild_id, announcement_since FROM guild_config
+        WHERE announcement_channel_id IS NOT NULL
+        "
+    )
+    .fetch_all(db)
+    .await?;
+
+    // Members of several guilds are only checked once, then announced in each of them.
+    let mut tracked: HashMap<(u32, GameMode), Vec<AnnouncementTarget>> = HashMap::new();
+
+    for guild in guilds {
+        let guild_id = GuildId(guild.guild_id);
+        let config = data.guild_config(guild_id).await?;
+
+        let (Some(channel_id), Some(since)) =
+            (config.announcement_channel, guild.announcement_since)
+        else {
+            continue;
+        };
+
+        let mode = config.default_mode.unwrap_or_default();
+        let locale = config.locale.unwrap_or_default();
+
+        for (member, osu_id) in linked_guild_members(ctx, db, guild_id).await? {
+            tracked
+                .entry((osu_id, mode))
+                .or_default()
+                .push(AnnouncementTarget {
+                    guild_id,
+                    channel_id,
+                    locale,
+                    member,
+                    since: since.unix_timestamp(),
+                });
+        }
+    }
+
+    for ((osu_id, mode), targets) in tracked {
+        let Ok(submit_mode) = SubmittableMode::try_from(mode) else {
+            continue;
+        };
+
+        if let Err(e) = track_user(ctx, data, osu_id, submit_mode, &targets).await {
+            error!("{e:?}");
+        }
+
+        sleep(TRACKED_USER_DELAY).await;
+    }
+
+    Ok(())
+}
+
+async fn track_user(
+    ctx: &serenity_prelude::Context,
+    data: &rika_cord::Data,
+    osu_id: u32,
+    submit_mode: SubmittableMode,
+    targets: &[AnnouncementTarget],
+) -> Result<(), rika_cord::Error> {
+    let SharedRika {
+        db,
+        score_submitter,
+        locales,
+        ..
+    } = data.shared.as_ref();
+
+    let mode_bits = GameMode::from(submit_mode) as i16;
+
+    let before = weighted_skills(db, osu_id, submit_mode).await?;
+
+    let (submitter, ..) = ScoreSubmitter::begin_submission(score_submitter);
+
+    let top_plays = match submitter.submit_top_plays(osu_id, submit_mode.into()).await {
+        Ok(top_plays) => top_plays,
+        // Opted out users are skipped, users submitting right now are picked up on the next check.
+        Err(SubmissionError::OptedOut | SubmissionError::IdLocker(..)) => return Ok(()),
+        Err(e) => Err(e)?,
+    };
+
+    let Some(earliest_since) = targets.iter().map(|target| target.since).min() else {
+        return Ok(());
+    };
+
+    // Plays stored before this check, by a submit or the scraper, are still announced once.
+    let recent_plays = top_plays
+        .into_iter()
+        .filter(|play| play.score.ended_at.unix_timestamp() > earliest_since)
+        .collect_vec();
+
+    if recent_plays.is_empty() {
+        return Ok(());
+    }
+
+    let Some(after) = weighted_skills(db, osu_id, submit_mode).await? else {
+        return Ok(());
+    };
+
+    // Only the plays new to this check moved the skills, the others were stored with them.
+    let before = before.unwrap_or_else(|| after.clone());
+
+    for target in targets {
+        let i18n = locales.get(target.locale);
+        let skill_changes = skill_changes(&i18n, &before, &after);
+
+        for play in &recent_plays {
+            let Some(score_id) = play.score.score_id else {
+                continue;
+            };
+
+            if play.score.ended_at.unix_timestamp() <= target.since {
+                continue;
+            }
+
+            let announced = sqlx::query!(
+                "
+                INSERT IGNORE INTO top_play_announcement (guild_id, mode, score_id)
+                VALUES (?, ?, ?)
+                ",
+                &target.guild_id.0,
+                &mode_bits,
+                &score_id
+            )
+            .execute(db)
+            .await?;
+
+            // Already posted in this guild on an earlier check.
+            if announced.rows_affected() == 0 {
+                continue;
+            }
+
+            // Plays stored before this check moved the skills back then, not in this change.
+            let play_changes = play.new.then_some(skill_changes.as_str());
+
+            let sent = target
+                .channel_id
+                .send_message(ctx, |m| {
+                    m.embed(|e| top_play_embed(e, &i18n, &target.member, play, play_changes))
+                })
+                .await;
+
+            // A channel that went missing should not keep the other guilds from their posts.
+            if let Err(e) = sent {
+                error!("{e:?}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn skill_changes(
+    i18n: &LocaleAccess<Localizer<RikaLocale, RikaLocalizer>>,
+    (overall_before, skills_before): &SkillVector,
+    (overall_after, skills_after): &SkillVector,
+) -> String {
+    skills_after
+        .iter()
+        .map(|(axis, after)| {
+            let before = skills_before
+                .iter()
+                .find(|(before_axis, ..)| before_axis == axis)
+                .map_or(*after, |(.., before)| *before);
+
+            (*axis, before, *after)
+        })
+        .chain([("overall", *overall_before, *overall_after)])
+        .map(|(axis, before, after)| {
+            format!(
+                "{}: {} ({:+.2})",
+                axis_label(i18n, axis),
+                mono(format!("{after:.2}pp")),
+                after - before
+            )
+        })
+        .join("\n")
+}
+
+fn top_play_embed<'a>(
+    embed: &'a mut CreateEmbed,
+    i18n: &LocaleAccess<Localizer<RikaLocale, RikaLocalizer>>,
+    member: &Member,
+    play: &TopPlay,
+    skill_changes: Option<&str>,
+) -> &'a mut CreateEmbed {
+    t_prefix!($, i18n.osu.announce);
+
+    let score = &play.score;
+
+    let beatmap_name = match (&score.mapset, &score.map) {
+        (Some(mapset), Some(map)) => {
+            format!("{} - {} [{}]", mapset.artist, mapset.title, map.version)
+        }
+        _ => format!("#{}", score.map_id),
+    };
+
+    embed
+        .color(Color::PURPLE)
+        .title(format!(
+            "{} {}",
+            RikaMoji::Ok,
+            t!(top_play).r(member.display_name().to_string())
+        ))
+        .description(format!(
+            "[{beatmap_name}](https://osu.ppy.sh/b/{}) {}",
+            score.map_id,
+            mono(score.mods.to_string())
+        ))
+        .field(
+            t!(pp),
+            mono(format!("{:.2}pp", score.pp.unwrap_or_default())),
+            true,
+        )
+        .field(t!(position), t!(position_of).r(play.position), true);
+
+    if let Some(skill_changes) = skill_changes {
+        embed.field(t!(skills), skill_changes, false);
+    }
+
+    embed
+}
@@ -0,0 +1,168 @@
+use std::{
+    f32::consts::{FRAC_PI_2, TAU},
+    io::Cursor,
+};
+
+use image::{DynamicImage, ImageError, ImageOutputFormat, Rgb, RgbImage};
+use imageproc::drawing::{draw_filled_circle_mut, draw_line_segment_mut};
+use itertools::{Itertools, MinMaxResult};
+
+const WIDTH: u32 = 800;
+const HEIGHT: u32 = 400;
+const MARGIN: f32 = 32.0;
+const GRID_LINES: u32 = 4;
+const POINT_RADIUS: i32 = 3;
+
+const BACKGROUND: Rgb<u8> = Rgb([43, 45, 49]);
+const GRID: Rgb<u8> = Rgb([70, 73, 79]);
+
+/// Colours given to the series of a chart, in order, alongside the emoji shown in its legend.
+pub const SERIES_COLORS: [(Rgb<u8>, &str); 5] = [
+    (Rgb([237, 66, 69]), "🟥"),
+    (Rgb([88, 101, 242]), "🟦"),
+    (Rgb([87, 242, 135]), "🟩"),
+    (Rgb([254, 231, 92]), "🟨"),
+    (Rgb([155, 89, 182]), "🟪"),
+];
+
+pub struct ChartSeries {
+    pub color: Rgb<u8>,
+    pub points: Vec<(f64, f32)>,
+}
+
+fn encode_png(image: RgbImage) -> Result<Vec<u8>, ImageError> {
+    let mut bytes = vec![];
+    DynamicImage::ImageRgb8(image)
+        .write_to(&mut Cursor::new(&mut bytes), ImageOutputFormat::Png)?;
+
+    Ok(bytes)
+}
+
+/// Draws every series as a line over a shared x range, with the y axis starting at zero.
+///
+/// There are no labels, since there are no fonts to draw them with, so the values should be
+/// described next to the image.
+pub fn line_chart(series: &[ChartSeries]) -> Result<Vec<u8>, ImageError> {
+    let mut image = RgbImage::from_pixel(WIDTH, HEIGHT, BACKGROUND);
+
+    let (min_x, max_x) = match series
+        .iter()
+        .flat_map(|s| &s.points)
+        .map(|(x, ..)| *x)
+        .minmax()
+    {
+        MinMaxResult::MinMax(min, max) => (min, max),
+        MinMaxResult::OneElement(x) => (x - 1.0, x + 1.0),
+        MinMaxResult::NoElements => (0.0, 1.0),
+    };
+
+    let max_y = series
+        .iter()
+        .flat_map(|s| &s.points)
+        .map(|(.., y)| *y)
+        .fold(1.0, f32::max)
+        * 1.1;
+
+    let plot_width = WIDTH as f32 - MARGIN * 2.0;
+    let plot_height = HEIGHT as f32 - MARGIN * 2.0;
+
+    let project = |(x, y): (f64, f32)| {
+        (
+            MARGIN + ((x - min_x) / (max_x - min_x)) as f32 * plot_width,
+            MARGIN + (1.0 - y / max_y) * plot_height,
+        )
+    };
+
+    for line in 0..=GRID_LINES {
+        let y = MARGIN + plot_height * line as f32 / GRID_LINES as f32;
+        draw_line_segment_mut(&mut image, (MARGIN, y), (WIDTH as f32 - MARGIN, y), GRID);
+    }
+
+    for ChartSeries { color, points } in series {
+        for (from, to) in points.iter().map(|p| project(*p)).tuple_windows() {
+            draw_line_segment_mut(&mut image, from, to, *color);
+            draw_line_segment_mut(
+                &mut image,
+                (from.0, from.1 + 1.0),
+                (to.0, to.1 + 1.0),
+                *color,
+            );
+        }
+
+        for (x, y) in points.iter().map(|p| project(*p)) {
+            draw_filled_circle_mut(&mut image, (x as i32, y as i32), POINT_RADIUS, *color);
+        }
+    }
+
+    encode_png(image)
+}
+
+const RADAR_SIZE: u32 = 400;
+const RADAR_RINGS: u32 = 4;
+
+pub struct RadarSeries {
+    pub color: Rgb<u8>,
+    pub values: Vec<f32>,
+}
+
+/// Draws every series as a polygon with one corner per axis, each axis scaled so the largest
+/// value among the series reaches the edge.
+pub fn radar_chart(axes: usize, series: &[RadarSeries]) -> Result<Vec<u8>, ImageError> {
+    let mut image = RgbImage::from_pixel(RADAR_SIZE, RADAR_SIZE, BACKGROUND);
+
+    let centre = RADAR_SIZE as f32 / 2.0;
+    let radius = centre - MARGIN;
+
+    let corner = |axis: usize, scale: f32| {
+        let angle = -FRAC_PI_2 + TAU * axis as f32 / axes as f32;
+        (
+            centre + angle.cos() * radius * scale,
+            centre + angle.sin() * radius * scale,
+        )
+    };
+
+    for ring in 1..=RADAR_RINGS {
+        let scale = ring as f32 / RADAR_RINGS as f32;
+
+        for axis in 0..axes {
+            draw_line_segment_mut(
+                &mut image,
+                corner(axis, scale),
+                corner((axis + 1) % axes, scale),
+                GRID,
+            );
+        }
+    }
+
+    for axis in 0..axes {
+        draw_line_segment_mut(&mut image, (centre, centre), corner(axis, 1.0), GRID);
+    }
+
+    let maximums = (0..axes)
+        .map(|axis| {
+            series
+                .iter()
+                .filter_map(|s| s.values.get(axis).copied())
+                .fold(f32::EPSILON, f32::max)
+        })
+        .collect_vec();
+
+    for RadarSeries { color, values } in series {
+        let corners = values
+            .iter()
+            .zip(&maximums)
+            .enumerate()
+            .map(|(axis, (value, maximum))| corner(axis, value / maximum))
+            .collect_vec();
+
+        for (from, to) in corners.iter().circular_tuple_windows() {
+            draw_line_segment_mut(&mut image, *from, *to, *color);
+        }
+
+        for (x, y) in corners {
+            draw_filled_circle_mut(&mut image, (x as i32, y as i32), POINT_RADIUS, *color);
+        }
+    }
+
+    encode_png(image)
+}
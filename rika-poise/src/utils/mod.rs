@@ -1,3 +1,5 @@
+pub mod chart;
 pub mod emojis;
 pub mod markdown;
+pub mod paginate;
 pub mod replies;
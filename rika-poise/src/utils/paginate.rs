@@ -0,0 +1,136 @@
+use std::{sync::Arc, time::Duration};
+
+use poise::serenity_prelude::{
+    CollectComponentInteraction, Color, CreateComponents, CreateEmbed, InteractionResponseType,
+    MessageComponentInteraction,
+};
+use rika_model::rika_cord;
+
+use crate::commands::CommandReturn;
+
+/// How long the navigation buttons keep working after they were last pressed.
+const PAGINATION_TIMEOUT: Duration = Duration::from_secs(60 * 10);
+
+/// Navigation between the pages of a reply, only listening to the user that invoked the command.
+pub struct Paginator {
+    prefix: String,
+    pages: usize,
+    page: usize,
+}
+
+impl Paginator {
+    pub fn new(ctx: rika_cord::Context<'_>, pages: usize) -> Self {
+        Self {
+            prefix: format!("{}_", ctx.id()),
+            pages,
+            page: 0,
+        }
+    }
+
+    /// Starts the custom id of every button of the reply, so presses on other replies are ignored.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn paginated(&self) -> bool {
+        self.pages > 1
+    }
+
+    /// Adds the navigation buttons, only when there is more than one page.
+    pub fn navigation_row<'a>(
+        &self,
+        components: &'a mut CreateComponents,
+    ) -> &'a mut CreateComponents {
+        if self.paginated() {
+            components.create_action_row(|row| {
+                row.create_button(|b| b.custom_id(format!("{}prev", self.prefix)).emoji('◀'))
+                    .create_button(|b| b.custom_id(format!("{}next", self.prefix)).emoji('▶'))
+            });
+        }
+
+        components
+    }
+
+    /// Waits for the invoking user to press a button of the reply, turning the page on navigation
+    /// and returning any other press, or nothing once the buttons time out.
+    pub async fn next_press(
+        &mut self,
+        ctx: rika_cord::Context<'_>,
+        embed: impl Fn(&mut CreateEmbed, usize) -> &mut CreateEmbed,
+    ) -> Result<Option<Arc<MessageComponentInteraction>>, rika_cord::Error> {
+        while let Some(press) = CollectComponentInteraction::new(ctx)
+            .author_id(ctx.author().id)
+            .filter({
+                let prefix = self.prefix.clone();
+                move |press| press.data.custom_id.starts_with(&prefix)
+            })
+            .timeout(PAGINATION_TIMEOUT)
+            .await
+        {
+            match press.data.custom_id.strip_prefix(self.prefix.as_str()) {
+                Some("next") => self.page = (self.page + 1) % self.pages,
+                Some("prev") => self.page = self.page.checked_sub(1).unwrap_or(self.pages - 1),
+                _ => return Ok(Some(press)),
+            }
+
+            let page = self.page;
+
+            press
+                .create_interaction_response(ctx, |r| {
+                    r.kind(InteractionResponseType::UpdateMessage)
+                        .interaction_response_data(|d| d.embed(|e| embed(e, page)))
+                })
+                .await?;
+        }
+
+        Ok(None)
+    }
+}
+
+fn page_embed<'a>(
+    embed: &'a mut CreateEmbed,
+    title: &str,
+    pages: &[String],
+    page: usize,
+) -> &'a mut CreateEmbed {
+    embed
+        .color(Color::PURPLE)
+        .title(title)
+        .description(&pages[page])
+        .footer(|f| f.text(format!("{}/{}", page + 1, pages.len())))
+}
+
+/// Replies with an embed per page, only adding navigation buttons when there is more than one.
+pub async fn paginate_embed(
+    ctx: rika_cord::Context<'_>,
+    title: &str,
+    pages: &[String],
+) -> CommandReturn {
+    let mut paginator = Paginator::new(ctx, pages.len());
+
+    let reply = ctx
+        .send(|r| {
+            r.embed(|e| page_embed(e, title, pages, paginator.page()))
+                .components(|c| paginator.navigation_row(c))
+        })
+        .await?;
+
+    if !paginator.paginated() {
+        return Ok(());
+    }
+
+    // Only navigation buttons are sent, so no other press ever comes back.
+    while paginator
+        .next_press(ctx, |e, page| page_embed(e, title, pages, page))
+        .await?
+        .is_some()
+    {}
+
+    reply.edit(ctx, |r| r.components(|c| c)).await?;
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+-- Keeps track of where a score came from, since not every score is an api top play.
+ALTER TABLE osu_score
+    ADD COLUMN source VARCHAR(16) DEFAULT 'api' NOT NULL;
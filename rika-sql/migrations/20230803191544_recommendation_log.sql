@@ -0,0 +1,16 @@
+CREATE TABLE recommendation_log (
+    id SERIAL PRIMARY KEY NOT NULL,
+    mode SMALLINT NOT NULL,
+
+    osu_user_id INT UNSIGNED NOT NULL,
+
+    mods INT UNSIGNED NOT NULL,
+    map_id INT UNSIGNED NOT NULL,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    INDEX (osu_user_id, mode, map_id, created_at),
+    FOREIGN KEY (osu_user_id) REFERENCES osu_user (id) ON DELETE CASCADE
+);
+
+CREATE INDEX osu_score_user_map ON osu_score (osu_user_id, map_id);
@@ -0,0 +1,29 @@
+ALTER TABLE osu_score
+    ADD COLUMN mapper_id INT UNSIGNED;
+
+CREATE TABLE recommendation_feedback (
+    osu_user_id INT UNSIGNED NOT NULL,
+    map_id INT UNSIGNED NOT NULL,
+    mode SMALLINT NOT NULL,
+
+    mapper_id INT UNSIGNED,
+    kind VARCHAR(16) NOT NULL,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    PRIMARY KEY (osu_user_id, map_id, mode),
+    INDEX (osu_user_id, mode, mapper_id),
+    FOREIGN KEY (osu_user_id) REFERENCES osu_user (id) ON DELETE CASCADE
+);
+
+CREATE TABLE recommendation_tuning (
+    osu_user_id INT UNSIGNED NOT NULL,
+    mode SMALLINT NOT NULL,
+    axis VARCHAR(32) NOT NULL,
+
+    centre FLOAT DEFAULT 1 NOT NULL,
+    spread FLOAT DEFAULT 1 NOT NULL,
+
+    PRIMARY KEY (osu_user_id, mode, axis),
+    FOREIGN KEY (osu_user_id) REFERENCES osu_user (id) ON DELETE CASCADE
+);
@@ -0,0 +1,4 @@
+-- Recommendations rank candidates by distance, filtering them by overall pp first.
+CREATE INDEX osu_performance_overall ON osu_performance (overall);
+CREATE INDEX taiko_performance_overall ON taiko_performance (overall);
+CREATE INDEX mania_performance_overall ON mania_performance (overall);
@@ -0,0 +1,12 @@
+CREATE TABLE skill_snapshot (
+    osu_user_id INT UNSIGNED NOT NULL,
+    mode SMALLINT NOT NULL,
+    axis VARCHAR(32) NOT NULL,
+
+    value FLOAT NOT NULL,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    PRIMARY KEY (osu_user_id, mode, created_at, axis),
+    FOREIGN KEY (osu_user_id) REFERENCES osu_user (id) ON DELETE CASCADE
+);
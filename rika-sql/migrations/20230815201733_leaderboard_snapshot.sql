@@ -0,0 +1,12 @@
+CREATE TABLE leaderboard_snapshot (
+    guild_id BIGINT UNSIGNED NOT NULL,
+    mode SMALLINT NOT NULL,
+    axis VARCHAR(32) NOT NULL,
+    discord_id VARCHAR(255) NOT NULL,
+
+    position INT UNSIGNED NOT NULL,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    PRIMARY KEY (guild_id, mode, axis, discord_id)
+);
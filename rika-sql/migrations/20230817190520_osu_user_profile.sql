@@ -0,0 +1,5 @@
+ALTER TABLE osu_user
+    ADD COLUMN username VARCHAR(32),
+    ADD COLUMN country_code CHAR(2);
+
+CREATE INDEX osu_user_country ON osu_user (country_code);
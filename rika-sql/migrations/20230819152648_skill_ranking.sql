@@ -0,0 +1,11 @@
+CREATE TABLE skill_ranking (
+    mode SMALLINT NOT NULL,
+    axis VARCHAR(32) NOT NULL,
+    osu_user_id INT UNSIGNED NOT NULL,
+
+    value FLOAT NOT NULL,
+
+    PRIMARY KEY (mode, axis, osu_user_id),
+    INDEX (mode, axis, value),
+    FOREIGN KEY (osu_user_id) REFERENCES osu_user (id) ON DELETE CASCADE
+);
@@ -0,0 +1,10 @@
+CREATE TABLE scraper_cursor (
+    id TINYINT UNSIGNED PRIMARY KEY NOT NULL,
+
+    -- Empty for the global ranking.
+    country VARCHAR(2) DEFAULT '' NOT NULL,
+    mode SMALLINT NOT NULL,
+    page INT UNSIGNED NOT NULL,
+
+    updated_at TIMESTAMP DEFAULT NOW() ON UPDATE NOW() NOT NULL
+);
@@ -0,0 +1,26 @@
+-- When the scores of a user were last checked and stored, each mode is checked on its own.
+CREATE TABLE osu_user_mode (
+    osu_user_id INT UNSIGNED NOT NULL,
+    mode SMALLINT NOT NULL,
+
+    last_checked_at TIMESTAMP NULL,
+    last_submitted_at TIMESTAMP NULL,
+
+    PRIMARY KEY (osu_user_id, mode),
+    INDEX (mode, last_checked_at),
+    FOREIGN KEY (osu_user_id) REFERENCES osu_user (id) ON DELETE CASCADE
+);
+
+CREATE TABLE scraper_rank (
+    -- Empty for the global ranking.
+    country VARCHAR(2) DEFAULT '' NOT NULL,
+    mode SMALLINT NOT NULL,
+    osu_user_id INT UNSIGNED NOT NULL,
+
+    position INT UNSIGNED NOT NULL,
+    previous_position INT UNSIGNED,
+    changed_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    PRIMARY KEY (country, mode, osu_user_id),
+    FOREIGN KEY (osu_user_id) REFERENCES osu_user (id) ON DELETE CASCADE
+);
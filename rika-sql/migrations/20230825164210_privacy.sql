@@ -0,0 +1,2 @@
+ALTER TABLE osu_user
+    ADD COLUMN opted_out BOOLEAN DEFAULT FALSE NOT NULL;
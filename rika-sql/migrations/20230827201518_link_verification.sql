@@ -0,0 +1,13 @@
+-- Links made before verification existed stay unverified.
+ALTER TABLE rika_user
+    ADD COLUMN verified_at TIMESTAMP NULL;
+
+CREATE TABLE link_verification (
+    code CHAR(6) PRIMARY KEY NOT NULL,
+
+    discord_id VARCHAR(255) UNIQUE NOT NULL,
+    osu_id INT UNSIGNED NOT NULL,
+    username VARCHAR(32) NOT NULL,
+
+    expires_at TIMESTAMP NOT NULL
+);
@@ -0,0 +1,13 @@
+-- An audit trail of every link and unlink, the current link still lives in rika_user.
+CREATE TABLE link_event (
+    id SERIAL PRIMARY KEY NOT NULL,
+
+    discord_id VARCHAR(255) NOT NULL,
+    osu_id INT UNSIGNED NOT NULL,
+    action VARCHAR(16) NOT NULL,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    INDEX (discord_id),
+    INDEX (osu_id)
+);
@@ -0,0 +1,32 @@
+-- Settings of a guild, a NULL setting falls back to the bot's default.
+CREATE TABLE guild_config (
+    guild_id BIGINT UNSIGNED PRIMARY KEY NOT NULL,
+    default_mode SMALLINT,
+    locale VARCHAR(8),
+    announcement_channel_id BIGINT UNSIGNED,
+    -- Only top plays set after the announcement channel was picked are posted.
+    announcement_since TIMESTAMP NULL,
+    recommend_range FLOAT,
+
+    updated_at TIMESTAMP DEFAULT NOW() ON UPDATE NOW() NOT NULL
+);
+
+-- Commands turned off in a guild, by their full name like `osu recommend`.
+CREATE TABLE guild_disabled_command (
+    guild_id BIGINT UNSIGNED NOT NULL,
+    command VARCHAR(64) NOT NULL,
+
+    PRIMARY KEY (guild_id, command)
+);
+
+-- Top plays already posted in a guild, so a play is never announced twice however it was stored.
+CREATE TABLE top_play_announcement (
+    guild_id BIGINT UNSIGNED NOT NULL,
+    -- Score ids are only unique within a mode.
+    mode SMALLINT NOT NULL,
+    score_id BIGINT UNSIGNED NOT NULL,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    PRIMARY KEY (guild_id, mode, score_id)
+);
@@ -0,0 +1,4 @@
+-- The star rating of the beatmap with the score's mods, scores stored before are left without
+-- one until they are submitted again.
+ALTER TABLE osu_score
+    ADD COLUMN stars FLOAT;
@@ -0,0 +1,8 @@
+-- Users seen using a command in a guild, so its linked members can be listed without asking
+-- Discord about every linked user.
+CREATE TABLE guild_member (
+    guild_id BIGINT UNSIGNED NOT NULL,
+    discord_id VARCHAR(255) NOT NULL,
+
+    PRIMARY KEY (guild_id, discord_id)
+);
@@ -14,6 +14,11 @@ use strum::{Display, EnumIter, IntoEnumIterator};
 pub trait RoriconMetaTrait<K: Eq + Hash + Default + Copy, V: DefaultLocalizer> {
     // Returns references to the required locales.
     fn locales(&self) -> &Localizer<K, V>;
+
+    // A locale to use instead of the one of the user, if any.
+    fn locale_override(&self) -> Option<K> {
+        None
+    }
 }
 
 /// Automatically implemented trait for context's that provide locales.
@@ -33,7 +38,9 @@ for poise::Context<'a, U, E>
     }
 
     fn i18n_explicit(&self, localizer: &Localizer<K, V>) -> LocaleAccess<Localizer<K, V>> {
-        let key: K = LocaleKey::from(self.locale()).0;
+        let key: K = self
+            .locale_override()
+            .unwrap_or_else(|| LocaleKey::from(self.locale()).0);
         localizer.get(key)
     }
 }
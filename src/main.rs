@@ -4,7 +4,8 @@ use dotenvy::dotenv;
 use lexicon::Localizer;
 use rika_model::{
     i18n::{pt_br::locale_pt_br, RikaLocale},
-    osu::{beatmap::BeatmapCache, submit::ScoreSubmitter},
+    osu::{beatmap::BeatmapCache, link::LinkPolicy, submit::ScoreSubmitter},
+    scraper::{config::ScraperConfig, handle::ScraperHandle, Scraper},
     SharedRika,
 };
 use serde::Deserialize;
@@ -16,6 +17,10 @@ pub struct RikaConfig {
     osu_client_id: u64,
     osu_client_secret: String,
     database_url: String,
+    #[serde(default)]
+    link_policy: LinkPolicy,
+    /// The single country scraped before `SCRAPER_COUNTRIES` existed, used when that is unset.
+    scraped_country: Option<String>,
 }
 
 #[tokio::main]
@@ -39,12 +44,26 @@ pub async fn main() {
         .await
         .expect("Failed to connect to database!");
 
+    let mut scraper_config = envy::prefixed("SCRAPER_")
+        .from_env::<ScraperConfig>()
+        .expect("Failed to read the scraper config!");
+
+    if scraper_config.countries.is_empty() {
+        scraper_config.countries.extend(config.scraped_country);
+    }
+
+    if let Err(e) = scraper_config.validate() {
+        panic!("Invalid scraper config: {e}");
+    }
+
     let shared_data = Arc::new(SharedRika {
         db,
         rosu,
         beatmap_cache: BeatmapCache::new(),
         score_submitter: Arc::new(RwLock::new(ScoreSubmitter::new())),
         locales,
+        scraper: ScraperHandle::new(scraper_config),
+        link_policy: config.link_policy,
     });
 
     shared_data
@@ -53,6 +72,8 @@ pub async fn main() {
         .await
         .provide_data(shared_data.clone());
 
+    tokio::spawn(Scraper::run(shared_data.clone()));
+
     let result_work = try_join!(
         rika_bancho::run(shared_data.clone()),
         rika_poise::run(shared_data.clone())